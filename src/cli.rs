@@ -8,23 +8,87 @@ pub enum GenerateType {
         #[arg(help = "Name of the entity")]
         name: String,
         #[arg(
-            long, 
-            help = "Fields in format: name:type|validation email:type|validation", 
-            num_args = 1..,
-            value_delimiter = ' '
+            long,
+            help = "Fields in format: name:type|validation, e.g. \"title:String|required\" \"bio?:String\" \"views:i32=0\"",
+            num_args = 1..
         )]
         fields: Vec<String>,
+        #[arg(
+            long,
+            help = "Generate an entity without fields instead of prompting for them when --fields is omitted"
+        )]
+        no_interactive: bool,
     },
     #[command(about = "Generate a new migration")]
     Migration {
         #[arg(help = "Name of the migration")]
         name: String,
+        #[arg(
+            long,
+            help = "Diff backend/src/entities against the last schema snapshot"
+        )]
+        from_entities: bool,
+        #[arg(
+            long,
+            requires = "from_entities",
+            help = "Diff against the live database (INFO FOR DB) instead of the snapshot"
+        )]
+        live: bool,
+        #[arg(
+            long = "type",
+            conflicts_with = "from_entities",
+            help = "SurrealQL type of an added column (default: the entity field type, else string)"
+        )]
+        type_: Option<String>,
+        #[arg(
+            long,
+            conflicts_with = "from_entities",
+            help = "DEFAULT clause of an added column"
+        )]
+        default: Option<String>,
+        #[arg(
+            long,
+            conflicts_with = "from_entities",
+            help = "ASSERT clause of an added column, e.g. '$value >= 0'"
+        )]
+        assert: Option<String>,
+        #[arg(
+            long,
+            conflicts_with = "from_entities",
+            help = "Back up a removed column's values so a rollback restores them"
+        )]
+        backup: bool,
     },
     #[command(about = "Generate a new resolver")]
     Resolver {
         #[arg(help = "Name of the resolver")]
         name: String,
     },
+    #[command(about = "Generate the repository and service of an existing entity")]
+    Service {
+        #[arg(help = "Name of the entity")]
+        name: String,
+    },
+    #[command(about = "Generate an entity with its repository, service, migration and resolver")]
+    Scaffold {
+        #[arg(help = "Name of the entity")]
+        name: String,
+        #[arg(
+            long,
+            help = "Fields in format: name:type|validation, e.g. \"title:String|required\" \"bio?:String\" \"views:i32=0\"",
+            num_args = 1..
+        )]
+        fields: Vec<String>,
+        #[arg(long, help = "Also generate Remix list, detail and edit routes")]
+        routes: bool,
+    },
+    #[command(about = "Generate fake seed data from an entity")]
+    Seed {
+        #[arg(help = "Name of the entity")]
+        name: String,
+        #[arg(long, default_value = "10", help = "Number of records to generate")]
+        count: usize,
+    },
 }
 
 #[derive(Subcommand)]
@@ -32,8 +96,24 @@ pub enum DbCommand {
     #[command(about = "List all migrations")]
     List,
 
+    #[command(about = "Show applied and pending migrations")]
+    Status,
+
     #[command(about = "Run pending migrations")]
-    Migrate,
+    Migrate {
+        #[arg(
+            long,
+            help = "Run all pending migrations in a single transaction instead of one per migration"
+        )]
+        batch: bool,
+        #[arg(
+            long,
+            help = "Print the migrations and their SurrealQL without connecting to the database"
+        )]
+        dry_run: bool,
+        #[arg(long, help = "Migrate up or down to this version")]
+        to: Option<String>,
+    },
 
     #[command(about = "Rollback migrations")]
     Rollback {
@@ -41,9 +121,36 @@ pub enum DbCommand {
             short,
             long,
             help = "Number of migrations to rollback",
-            default_value = "1"
+            default_value = "1",
+            conflicts_with = "to"
         )]
         steps: u32,
+        #[arg(long, help = "Rollback every migration applied after this version")]
+        to: Option<String>,
+        #[arg(
+            long,
+            help = "Print the migrations and their SurrealQL without connecting to the database"
+        )]
+        dry_run: bool,
+    },
+
+    #[command(about = "Rollback every migration and apply them again")]
+    Reset {
+        #[arg(
+            long,
+            help = "Remove the whole database instead of rolling back each migration"
+        )]
+        drop: bool,
+    },
+
+    #[command(about = "Run the database/seeds/*.surql files of an environment")]
+    Seed {
+        #[arg(
+            long,
+            help = "Run database/seeds/<env>/*.surql and the root seeds declaring <env>; must match DB_ENV",
+            default_value = crate::db::seeder::DEFAULT_ENV
+        )]
+        env: String,
     },
 }
 
@@ -56,8 +163,16 @@ pub enum Commands {
     },
     #[command(about = "Generate project components")]
     Generate {
+        #[arg(
+            long,
+            value_name = "FILE",
+            num_args = 0..=1,
+            default_missing_value = crate::generators::schema_file::SCHEMA_PATH,
+            help = "Regenerate every entity declared in the schema file (default: nebulis.schema.toml)"
+        )]
+        from_schema: Option<String>,
         #[command(subcommand)]
-        type_: GenerateType,
+        type_: Option<GenerateType>,
     },
     #[command(about = "Database operations")]
     Db {
@@ -80,13 +195,51 @@ impl Cli {
             Commands::New { name } => {
                 crate::commands::new::execute(name);
             }
-            Commands::Generate { type_ } => {
-                let result = match type_ {
-                    GenerateType::Entity { name, fields } => {
-                        crate::generators::entity::execute(name, fields)
+            Commands::Generate { from_schema, type_ } => {
+                let result = match (from_schema, type_) {
+                    (Some(path), None) => crate::generators::schema_file::execute(path),
+                    (Some(_), Some(_)) => {
+                        Err("--from-schema cannot be combined with a generate subcommand".into())
                     }
-                    GenerateType::Migration { name } => crate::generators::migration::execute(name),
-                    GenerateType::Resolver { name } => crate::generators::resolver::execute(name),
+                    (None, None) => Err("Missing generate subcommand (or --from-schema)".into()),
+                    (None, Some(type_)) => match type_ {
+                        GenerateType::Entity {
+                            name,
+                            fields,
+                            no_interactive,
+                        } => crate::generators::entity::execute(name, fields, !no_interactive),
+                        GenerateType::Migration {
+                            name,
+                            from_entities,
+                            live,
+                            type_,
+                            default,
+                            assert,
+                            backup,
+                        } => crate::generators::migration::execute(
+                            name,
+                            &crate::generators::migration::MigrationOptions {
+                                from_entities: *from_entities,
+                                live: *live,
+                                type_: type_.clone(),
+                                default: default.clone(),
+                                assert: assert.clone(),
+                                backup: *backup,
+                            },
+                        ),
+                        GenerateType::Resolver { name } => {
+                            crate::generators::resolver::execute(name)
+                        }
+                        GenerateType::Service { name } => crate::generators::service::execute(name),
+                        GenerateType::Scaffold {
+                            name,
+                            fields,
+                            routes,
+                        } => crate::generators::scaffold::execute(name, fields, *routes),
+                        GenerateType::Seed { name, count } => {
+                            crate::generators::seed::execute(name, *count)
+                        }
+                    },
                 };
 
                 if let Err(e) = result {
@@ -97,10 +250,15 @@ impl Cli {
             Commands::Db { command } => {
                 let result = match command {
                     DbCommand::List => crate::commands::db::execute_list(),
-                    DbCommand::Migrate => crate::commands::db::execute_migrate(),
-                    DbCommand::Rollback { steps } => {
-                        crate::commands::db::execute_rollback(Some(*steps))
+                    DbCommand::Status => crate::commands::db::execute_status(),
+                    DbCommand::Migrate { batch, dry_run, to } => {
+                        crate::commands::db::execute_migrate(*batch, *dry_run, to.as_deref())
+                    }
+                    DbCommand::Rollback { steps, to, dry_run } => {
+                        crate::commands::db::execute_rollback(Some(*steps), to.as_deref(), *dry_run)
                     }
+                    DbCommand::Reset { drop } => crate::commands::db::execute_reset(*drop),
+                    DbCommand::Seed { env } => crate::commands::db::execute_seed(env),
                 };
 
                 if let Err(e) = result {
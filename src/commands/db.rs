@@ -1,5 +1,6 @@
 // src/commands/db.rs
-use crate::generators::migration;
+use crate::db::migrator::{RollbackTarget, TransactionMode};
+use crate::generators::{migration, seed};
 use colored::*;
 
 pub fn execute_list() -> Result<(), String> {
@@ -8,20 +9,61 @@ pub fn execute_list() -> Result<(), String> {
     Ok(())
 }
 
-pub fn execute_migrate() -> Result<(), String> {
+pub fn execute_status() -> Result<(), String> {
+    println!("{}", "Migration status:".blue());
+    migration::show_status()?;
+    Ok(())
+}
+
+pub fn execute_migrate(batch: bool, dry_run: bool, to: Option<&str>) -> Result<(), String> {
+    if dry_run {
+        println!("{}", "Planned migrations:".blue());
+        return migration::preview_migrations(to);
+    }
+
     println!("{}", "Running migrations...".blue());
-    migration::execute_pending_migrations()?;
+    let mode = if batch {
+        TransactionMode::Batch
+    } else {
+        TransactionMode::PerMigration
+    };
+    migration::execute_pending_migrations(mode, to)?;
     println!("{} Migrations completed", "✓".green());
     Ok(())
 }
 
-pub fn execute_rollback(steps: Option<u32>) -> Result<(), String> {
-    let steps = steps.unwrap_or(1);
-    println!(
-        "{}",
-        format!("Rolling back {} migration(s)...", steps).blue()
-    );
-    migration::rollback_migrations(steps)?;
+pub fn execute_rollback(steps: Option<u32>, to: Option<&str>, dry_run: bool) -> Result<(), String> {
+    let target = match to {
+        Some(to) => RollbackTarget::To(to.to_string()),
+        None => RollbackTarget::Steps(steps.unwrap_or(1)),
+    };
+
+    if dry_run {
+        println!("{}", "Planned rollback:".blue());
+        return migration::preview_rollback(&target);
+    }
+
+    let message = match &target {
+        RollbackTarget::Steps(steps) => format!("Rolling back {} migration(s)...", steps),
+        RollbackTarget::To(to) => format!("Rolling back to version {}...", to),
+        RollbackTarget::All => "Rolling back all migrations...".to_string(),
+    };
+    println!("{}", message.blue());
+    migration::rollback_migrations(&target)?;
     println!("{} Rollback completed", "✓".green());
     Ok(())
 }
+
+pub fn execute_reset(drop: bool) -> Result<(), String> {
+    println!("{}", "Resetting database...".blue());
+    migration::reset_database(drop)?;
+    println!("{} Database reset", "✓".green());
+    Ok(())
+}
+
+pub fn execute_seed(env: &str) -> Result<(), String> {
+    println!("{}", format!("Seeding database ({})...", env).blue());
+    seed::run_seeds(env)?;
+    println!("{} Seeding completed", "✓".green());
+    Ok(())
+}
@@ -2,11 +2,11 @@
 use crate::generators::{migration, entity, resolver};
 
 pub fn execute_entity(name: &str, fields: &[String]) -> Result<(), String> {
-    entity::execute(name, fields)
+    entity::execute(name, fields, true)
 }
 
-pub fn execute_migration(name: &str) -> Result<(), String> {
-    migration::execute(name)
+pub fn execute_migration(name: &str, options: &migration::MigrationOptions) -> Result<(), String> {
+    migration::execute(name, options)
 }
 
 pub fn execute_resolver(name: &str) -> Result<(), String> {
@@ -20,6 +20,9 @@ pub fn execute(name: &str) {
     // Initialize Env file at root level only
     init_env(name);
 
+    // Initialize the entity schema file at root level only
+    init_schema(name);
+
     // Generate backend
     println!("\n{}", "Generating Rust backend...".blue());
     backend::create_structure(name);
@@ -91,6 +94,14 @@ database/
     }
 }
 
+pub fn init_schema(path: &str) {
+    fs::write(
+        format!("{}/{}", path, crate::generators::schema_file::SCHEMA_PATH),
+        crate::generators::schema_file::SCHEMA_TEMPLATE,
+    )
+    .unwrap_or_else(|_| panic!("Failed to create nebulis.schema.toml"));
+}
+
 pub fn init_env(path: &str) {
     let content = format!(
         r#"#Database environement
@@ -99,6 +110,7 @@ DB_PORT=8000
 DB_USER=root
 DB_PASSWORD=root
 DB_NAME={name}
+DB_ENV=development
 
 #Backend environement
 SERVER_HOST=127.0.0.1
@@ -0,0 +1,85 @@
+// src/db/ledger.rs
+use super::transaction::Transaction;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use surrealdb::engine::any::Any;
+use surrealdb::{Response, Surreal};
+
+/// Table SurrealDB qui garde la trace des migrations appliquées
+pub const LEDGER_TABLE: &str = "_nebulis_migrations";
+
+/// Une migration enregistrée dans le ledger
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub version: String,
+    pub name: String,
+    pub checksum: String,
+    pub applied_at: String,
+    pub duration_ms: u64,
+}
+
+/// Empreinte SHA-256 du contenu d'un fichier `.up.surql`
+pub fn checksum(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// Crée la table du ledger si elle n'existe pas encore
+pub async fn ensure(db: &Surreal<Any>) -> Result<(), String> {
+    db.query(format!(
+        "DEFINE TABLE IF NOT EXISTS {LEDGER_TABLE} SCHEMAFULL;
+         DEFINE FIELD IF NOT EXISTS version ON {LEDGER_TABLE} TYPE string;
+         DEFINE FIELD IF NOT EXISTS name ON {LEDGER_TABLE} TYPE string;
+         DEFINE FIELD IF NOT EXISTS checksum ON {LEDGER_TABLE} TYPE string;
+         DEFINE FIELD IF NOT EXISTS applied_at ON {LEDGER_TABLE} TYPE datetime;
+         DEFINE FIELD IF NOT EXISTS duration_ms ON {LEDGER_TABLE} TYPE int;
+         DEFINE INDEX IF NOT EXISTS idx_{LEDGER_TABLE}_version ON {LEDGER_TABLE} FIELDS version UNIQUE;"
+    ))
+    .await
+    .and_then(Response::check)
+    .map_err(|e| format!("Failed to create {}: {}", LEDGER_TABLE, e))?;
+
+    Ok(())
+}
+
+/// Migrations appliquées, de la plus ancienne à la plus récente
+pub async fn entries(db: &Surreal<Any>) -> Result<Vec<LedgerEntry>, String> {
+    ensure(db).await?;
+
+    let mut response = db
+        .query(format!(
+            "SELECT version, name, checksum, <string> applied_at AS applied_at, duration_ms \
+             FROM {LEDGER_TABLE} ORDER BY version ASC"
+        ))
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", LEDGER_TABLE, e))?;
+
+    response
+        .take(0)
+        .map_err(|e| format!("Failed to read {}: {}", LEDGER_TABLE, e))
+}
+
+/// Ajoute à la transaction l'enregistrement de la `n`-ième migration
+///
+/// `$started_<n>` doit avoir été défini au début de la migration pour en mesurer la durée.
+pub fn record_in(tx: &mut Transaction, n: usize, version: &str, name: &str, checksum: &str) {
+    tx.push_internal(
+        &format!("Migration {}", name),
+        format!(
+            "CREATE {LEDGER_TABLE} SET version = $version_{n}, name = $name_{n}, \
+             checksum = $checksum_{n}, applied_at = time::now(), \
+             duration_ms = duration::millis(time::now() - $started_{n})"
+        ),
+    );
+    tx.bind(format!("version_{n}"), version);
+    tx.bind(format!("name_{n}"), name);
+    tx.bind(format!("checksum_{n}"), checksum);
+}
+
+/// Ajoute à la transaction la suppression d'une migration du ledger
+pub fn remove_in(tx: &mut Transaction, version: &str, name: &str) {
+    tx.push_internal(
+        &format!("Rollback {}", name),
+        format!("DELETE {LEDGER_TABLE} WHERE version = $version"),
+    );
+    tx.bind("version".to_string(), version);
+}
@@ -0,0 +1,378 @@
+// src/db/migrator.rs
+use super::ledger;
+use super::transaction::{split_statements, Transaction};
+use colored::*;
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use surrealdb::engine::any::Any;
+use surrealdb::Surreal;
+
+/// Une migration présente sur le disque (`database/schema/<name>.up.surql`)
+#[derive(Debug, Clone)]
+pub struct MigrationFile {
+    pub version: String,
+    pub name: String,
+    pub up_path: PathBuf,
+}
+
+/// Nom de fichier d'une migration : `<version>_<name>`
+pub fn file_stem(version: &str, name: &str) -> String {
+    format!("{}_{}", version, name)
+}
+
+/// Liste les migrations du projet, triées par leur préfixe de version
+pub fn discover(root: &Path) -> Result<Vec<MigrationFile>, String> {
+    let schema_dir = root.join("database/schema");
+    if !schema_dir.exists() {
+        return Err("No database/schema directory found".into());
+    }
+
+    let entries =
+        fs::read_dir(&schema_dir).map_err(|e| format!("Failed to read schema directory: {}", e))?;
+
+    let file_regex = Regex::new(r"^(\d{14})_(\w+)\.up\.surql$").unwrap();
+    let mut migrations: Vec<MigrationFile> = Vec::new();
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if !file_name.ends_with(".up.surql") {
+            continue;
+        }
+
+        let cap = file_regex.captures(&file_name).ok_or_else(|| {
+            format!(
+                "Invalid migration file name: {} (expected <YYYYmmddHHMMSS>_<name>.up.surql)",
+                file_name
+            )
+        })?;
+
+        let version = cap[1].to_string();
+        if let Some(existing) = migrations.iter().find(|m| m.version == version) {
+            return Err(format!(
+                "Migrations {} and {} share version {}",
+                existing.name, &cap[2], version
+            ));
+        }
+
+        migrations.push(MigrationFile {
+            version,
+            name: cap[2].to_string(),
+            up_path: entry.path(),
+        });
+    }
+
+    migrations.sort_by(|a, b| a.version.cmp(&b.version));
+    Ok(migrations)
+}
+
+/// Portée des transactions lors de l'application des migrations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionMode {
+    /// Une transaction par migration : les migrations réussies restent appliquées
+    PerMigration,
+    /// Une seule transaction pour toutes les migrations en attente
+    Batch,
+}
+
+/// Applique dans l'ordre toutes les migrations non encore appliquées
+///
+/// Chaque migration est enregistrée dans le ledger au sein de sa transaction ;
+/// en cas d'échec la transaction est annulée et le ledger reste inchangé.
+pub async fn apply_pending(
+    db: &Surreal<Any>,
+    root: &Path,
+    mode: TransactionMode,
+    to: Option<&str>,
+) -> Result<Vec<String>, String> {
+    let pending = plan_up(root, to, &ledger::entries(db).await?)?;
+
+    let mut tx = Transaction::new();
+    let mut batch = Vec::new();
+    let mut done = Vec::new();
+    let started = Instant::now();
+
+    for (n, (migration, sql)) in pending.into_iter().enumerate() {
+        let source = format!("Migration {}", migration.name);
+        tx.push_internal(&source, format!("LET $started_{n} = time::now()"));
+        for (number, statement) in split_statements(&sql).iter().enumerate() {
+            tx.push(&source, number + 1, statement);
+        }
+        ledger::record_in(
+            &mut tx,
+            n,
+            &migration.version,
+            &migration.name,
+            &ledger::checksum(&sql),
+        );
+
+        match mode {
+            TransactionMode::PerMigration => {
+                let started = Instant::now();
+                std::mem::take(&mut tx)
+                    .commit(db)
+                    .await
+                    .map_err(|e| format!("{}, ledger unchanged", e))?;
+                println!(
+                    "  {} {} ({} ms)",
+                    "✓".green(),
+                    migration.name,
+                    started.elapsed().as_millis()
+                );
+                done.push(migration.name);
+            }
+            TransactionMode::Batch => batch.push(migration.name),
+        }
+    }
+
+    if !batch.is_empty() {
+        tx.commit(db)
+            .await
+            .map_err(|e| format!("{}, ledger unchanged", e))?;
+        for name in batch {
+            println!("  {} {}", "✓".green(), name);
+            done.push(name);
+        }
+        println!("  Batch committed in {} ms", started.elapsed().as_millis());
+    }
+
+    Ok(done)
+}
+
+/// Cible d'un rollback
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RollbackTarget {
+    /// Les `n` dernières migrations appliquées
+    Steps(u32),
+    /// Toutes les migrations postérieures à cette version
+    To(String),
+    /// Toutes les migrations appliquées
+    All,
+}
+
+impl RollbackTarget {
+    /// Sélectionne, de la plus récente à la plus ancienne, les migrations à annuler
+    fn select<T>(&self, applied: Vec<T>, version: impl Fn(&T) -> &str) -> Vec<T> {
+        let newest_first = applied.into_iter().rev();
+        match self {
+            Self::Steps(steps) => newest_first.take(*steps as usize).collect(),
+            Self::To(target) => newest_first
+                .take_while(|m| version(m) > target.as_str())
+                .collect(),
+            Self::All => newest_first.collect(),
+        }
+    }
+}
+
+/// Vérifie qu'une version cible `--to` correspond à une migration du projet
+pub fn check_target(root: &Path, to: &str) -> Result<(), String> {
+    if discover(root)?.iter().any(|m| m.version == to) {
+        Ok(())
+    } else {
+        Err(format!("Unknown migration version: {}", to))
+    }
+}
+
+/// Migrations à exécuter, avec le SurrealQL de chacune ; un dry-run affiche le même plan
+pub type Plan = Vec<(MigrationFile, String)>;
+
+/// Migrations du disque non encore appliquées jusqu'à `to` inclus, avec leur `.up.surql`
+pub fn plan_up(
+    root: &Path,
+    to: Option<&str>,
+    applied: &[ledger::LedgerEntry],
+) -> Result<Plan, String> {
+    let mut plan = Vec::new();
+    for migration in discover(root)? {
+        if to.is_some_and(|to| migration.version.as_str() > to) {
+            break;
+        }
+        if applied
+            .iter()
+            .any(|entry| entry.version == migration.version)
+        {
+            continue;
+        }
+        let sql = fs::read_to_string(&migration.up_path)
+            .map_err(|e| format!("Failed to read {}: {}", migration.up_path.display(), e))?;
+        plan.push((migration, sql));
+    }
+
+    Ok(plan)
+}
+
+/// Migrations appliquées que vise un rollback, de la plus récente à la plus ancienne, avec
+/// leur `.down.surql` ; tous les fichiers sont lus avant que quoi que ce soit ne s'exécute
+pub fn plan_down(
+    root: &Path,
+    target: &RollbackTarget,
+    applied: &[ledger::LedgerEntry],
+) -> Result<Plan, String> {
+    target
+        .select(applied.to_vec(), |entry| entry.version.as_str())
+        .into_iter()
+        .map(|entry| {
+            let sql = read_down(root, &entry.version, &entry.name)?;
+            let up_path = root.join(format!(
+                "database/schema/{}.up.surql",
+                file_stem(&entry.version, &entry.name)
+            ));
+            let migration = MigrationFile {
+                version: entry.version,
+                name: entry.name,
+                up_path,
+            };
+            Ok((migration, sql))
+        })
+        .collect()
+}
+
+/// Plan d'un `migrate [--to]` : les migrations appliquées postérieures à la cible sont
+/// annulées (`.down.surql`), puis celles en attente jusqu'à la cible sont appliquées
+pub fn plan_to(
+    root: &Path,
+    to: Option<&str>,
+    applied: &[ledger::LedgerEntry],
+) -> Result<(Plan, Plan), String> {
+    let down = match to {
+        Some(to) => plan_down(root, &RollbackTarget::To(to.to_string()), applied)?,
+        None => Vec::new(),
+    };
+    Ok((down, plan_up(root, to, applied)?))
+}
+
+/// Lit le `.down.surql` d'une migration, en refusant un fichier absent ou vide
+fn read_down(root: &Path, version: &str, name: &str) -> Result<String, String> {
+    let down_path = root.join(format!(
+        "database/schema/{}.down.surql",
+        file_stem(version, name)
+    ));
+    let sql = fs::read_to_string(&down_path).map_err(|_| {
+        format!(
+            "Cannot rollback {}: {} is missing",
+            name,
+            down_path.display()
+        )
+    })?;
+
+    if sql.trim().is_empty() {
+        return Err(format!(
+            "Cannot rollback {}: {} is empty",
+            name,
+            down_path.display()
+        ));
+    }
+
+    Ok(sql)
+}
+
+/// Annule les migrations visées par `plan_down`, de la plus récente à la plus ancienne
+///
+/// Chaque migration est annulée et retirée du ledger dans une même transaction.
+pub async fn rollback(
+    db: &Surreal<Any>,
+    root: &Path,
+    target: &RollbackTarget,
+) -> Result<Vec<String>, String> {
+    let targets = plan_down(root, target, &ledger::entries(db).await?)?;
+
+    let mut done = Vec::new();
+    for (entry, sql) in targets {
+        let source = format!("Rollback {}", entry.name);
+        let mut tx = Transaction::new();
+        for (number, statement) in split_statements(&sql).iter().enumerate() {
+            tx.push(&source, number + 1, statement);
+        }
+        ledger::remove_in(&mut tx, &entry.version, &entry.name);
+        tx.commit(db)
+            .await
+            .map_err(|e| format!("{}, ledger unchanged", e))?;
+
+        println!("  {} {}", "↩".green(), entry.name);
+        done.push(entry.name);
+    }
+
+    Ok(done)
+}
+
+/// `migrate [--to]` : redescend d'abord sous une cible antérieure aux migrations appliquées,
+/// puis applique les migrations en attente ; renvoie les migrations annulées et appliquées
+pub async fn migrate(
+    db: &Surreal<Any>,
+    root: &Path,
+    mode: TransactionMode,
+    to: Option<&str>,
+) -> Result<(Vec<String>, Vec<String>), String> {
+    let rolled_back = match to {
+        Some(to) => rollback(db, root, &RollbackTarget::To(to.to_string())).await?,
+        None => Vec::new(),
+    };
+    let applied = apply_pending(db, root, mode, to).await?;
+    Ok((rolled_back, applied))
+}
+
+/// État d'une migration par rapport au ledger
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationState {
+    Applied,
+    Pending,
+    MissingFile,
+}
+
+/// Ligne du tableau affiché par `nebulis db status`
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub version: String,
+    pub name: String,
+    pub state: MigrationState,
+    pub applied_at: Option<String>,
+    pub drifted: bool,
+}
+
+/// Compare les migrations du disque avec celles du ledger
+pub fn status(
+    root: &Path,
+    applied: &[ledger::LedgerEntry],
+) -> Result<Vec<MigrationStatus>, String> {
+    let files = discover(root)?;
+    let mut rows = Vec::new();
+
+    for file in &files {
+        let entry = applied.iter().find(|entry| entry.version == file.version);
+        let drifted = match entry {
+            Some(entry) => {
+                let sql = fs::read_to_string(&file.up_path)
+                    .map_err(|e| format!("Failed to read {}: {}", file.up_path.display(), e))?;
+                ledger::checksum(&sql) != entry.checksum
+            }
+            None => false,
+        };
+
+        rows.push(MigrationStatus {
+            version: file.version.clone(),
+            name: file.name.clone(),
+            state: if entry.is_some() {
+                MigrationState::Applied
+            } else {
+                MigrationState::Pending
+            },
+            applied_at: entry.map(|entry| entry.applied_at.clone()),
+            drifted,
+        });
+    }
+
+    for entry in applied {
+        if !files.iter().any(|file| file.version == entry.version) {
+            rows.push(MigrationStatus {
+                version: entry.version.clone(),
+                name: entry.name.clone(),
+                state: MigrationState::MissingFile,
+                applied_at: Some(entry.applied_at.clone()),
+                drifted: false,
+            });
+        }
+    }
+
+    rows.sort_by(|a, b| a.version.cmp(&b.version));
+    Ok(rows)
+}
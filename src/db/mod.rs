@@ -0,0 +1,155 @@
+// src/db/mod.rs
+pub mod ledger;
+pub mod migrator;
+pub mod schema;
+pub mod seeder;
+pub mod transaction;
+
+use std::collections::HashMap;
+use std::fs;
+use std::future::Future;
+use std::path::Path;
+use surrealdb::engine::any::{self, Any};
+use surrealdb::opt::auth::Root;
+use surrealdb::{Response, Surreal};
+
+/// Paramètres de connexion lus depuis le `.env` du projet
+#[derive(Debug, Clone)]
+pub struct DbConfig {
+    pub host: String,
+    pub port: String,
+    pub user: String,
+    pub password: String,
+    pub namespace: String,
+    pub database: String,
+    /// Environnement de la base (`DB_ENV`), que doit viser `nebulis db seed --env`
+    pub env: Option<String>,
+}
+
+impl DbConfig {
+    /// Charge la configuration depuis `<root>/.env`, les variables d'environnement ayant priorité
+    pub fn load(root: &Path) -> Result<Self, String> {
+        let mut vars = read_env_file(&root.join(".env"));
+        for (key, value) in std::env::vars() {
+            if key.starts_with("DB_") {
+                vars.insert(key, value);
+            }
+        }
+
+        let get = |key: &str| {
+            vars.get(key)
+                .cloned()
+                .ok_or_else(|| format!("Missing {} in .env or environment", key))
+        };
+
+        let database = get("DB_NAME")?;
+        Ok(Self {
+            host: get("DB_HOST")?,
+            port: get("DB_PORT")?,
+            user: get("DB_USER")?,
+            password: get("DB_PASSWORD")?,
+            namespace: vars
+                .get("DB_NAMESPACE")
+                .cloned()
+                .unwrap_or(database.clone()),
+            database,
+            env: vars.get("DB_ENV").cloned(),
+        })
+    }
+
+    /// Adresse du serveur ; un `DB_HOST` contenant déjà un schéma (ex. `wss://`) est utilisé tel quel
+    pub fn endpoint(&self) -> String {
+        if self.host.contains("://") {
+            self.host.clone()
+        } else {
+            format!("ws://{}:{}", self.host, self.port)
+        }
+    }
+
+    /// Base servie par cette machine (`127.0.0.1`, `localhost`, `mem://`, fichier local...)
+    pub fn is_local(&self) -> bool {
+        if !self.is_remote() {
+            return true;
+        }
+        let host = self.endpoint();
+        let host = host
+            .split_once("://")
+            .map_or(host.as_str(), |(_, rest)| rest);
+        ["localhost", "127.", "0.0.0.0", "[::1]"]
+            .iter()
+            .any(|local| host.starts_with(local))
+    }
+
+    fn is_remote(&self) -> bool {
+        ["ws://", "wss://", "http://", "https://"]
+            .iter()
+            .any(|scheme| self.endpoint().starts_with(scheme))
+    }
+}
+
+/// Parse un fichier `.env` simple (`KEY=VALUE`, commentaires `#`)
+fn read_env_file(path: &Path) -> HashMap<String, String> {
+    let content = fs::read_to_string(path).unwrap_or_default();
+
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| {
+            (
+                key.trim().to_string(),
+                value.trim().trim_matches('"').to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Ouvre une connexion authentifiée et sélectionne le namespace/la base du projet
+pub async fn connect(config: &DbConfig) -> Result<Surreal<Any>, String> {
+    let db = any::connect(config.endpoint())
+        .await
+        .map_err(|e| format!("Failed to connect to {}: {}", config.endpoint(), e))?;
+
+    if config.is_remote() {
+        db.signin(Root {
+            username: &config.user,
+            password: &config.password,
+        })
+        .await
+        .map_err(|e| format!("Failed to sign in as {}: {}", config.user, e))?;
+    }
+
+    db.use_ns(&config.namespace)
+        .use_db(&config.database)
+        .await
+        .map_err(|e| {
+            format!(
+                "Failed to select {}/{}: {}",
+                config.namespace, config.database, e
+            )
+        })?;
+
+    Ok(db)
+}
+
+/// Supprime la base du projet puis la sélectionne à nouveau, vide
+pub async fn drop_database(db: &Surreal<Any>, config: &DbConfig) -> Result<(), String> {
+    db.query(format!("REMOVE DATABASE IF EXISTS `{}`", config.database))
+        .await
+        .and_then(Response::check)
+        .map_err(|e| format!("Failed to remove database {}: {}", config.database, e))?;
+
+    db.use_db(&config.database)
+        .await
+        .map_err(|e| format!("Failed to select {}: {}", config.database, e))?;
+
+    Ok(())
+}
+
+/// Exécute une tâche asynchrone depuis les commandes synchrones de la CLI
+pub fn block_on<F: Future>(future: F) -> Result<F::Output, String> {
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| format!("Failed to start async runtime: {}", e))?;
+    Ok(runtime.block_on(future))
+}
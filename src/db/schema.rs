@@ -0,0 +1,637 @@
+// src/db/schema.rs
+use crate::generators::entity::{
+    find_enum, find_object, module_name, table_name, EntityInfo, EnumType, FieldValidation,
+    RelationType,
+};
+use convert_case::{Case, Casing};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use surrealdb::engine::any::Any;
+use surrealdb::Surreal;
+
+/// Dernier schéma connu, mis à jour à chaque migration générée depuis les entités
+pub const SNAPSHOT_PATH: &str = "database/schema.snapshot.json";
+
+/// Champs de timestamps ajoutés à chaque table
+pub const TIMESTAMP_FIELDS: [&str; 3] = ["created_at", "updated_at", "deleted_at"];
+
+/// Définition d'un champ SurrealDB, dans l'ordre des clauses de `INFO FOR TABLE`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldDef {
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assert: Option<String>,
+}
+
+impl FieldDef {
+    pub fn new(kind: &str) -> Self {
+        Self {
+            kind: kind.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Définition d'un champ d'entité : type SurrealQL et `ASSERT` issus de ses validations
+    pub fn from_entity_field(rust_type: &str, validation: &FieldValidation) -> Self {
+        Self::constrained(rust_type, validation, Vec::new())
+    }
+
+    /// Champ d'enum : une chaîne limitée aux valeurs de ses variantes
+    pub fn from_enum_field(
+        rust_type: &str,
+        enum_type: &EnumType,
+        validation: &FieldValidation,
+    ) -> Self {
+        let values = enum_type
+            .values()
+            .iter()
+            .map(|value| format!("'{}'", value))
+            .collect::<Vec<_>>();
+        // La valeur par défaut désigne une variante, stockée en snake_case
+        let validation = FieldValidation {
+            default: validation
+                .default
+                .as_ref()
+                .map(|value| value.to_case(Case::Snake)),
+            ..validation.clone()
+        };
+        Self::constrained(
+            &rust_type.replace(&enum_type.name, "String"),
+            &validation,
+            vec![format!("$value INSIDE [{}]", values.join(", "))],
+        )
+    }
+
+    fn constrained(
+        rust_type: &str,
+        validation: &FieldValidation,
+        mut asserts: Vec<String>,
+    ) -> Self {
+        let kind = surql_type(rust_type);
+
+        if validation.required {
+            asserts.push("$value != NONE".to_string());
+        }
+        if validation.email || rust_type.trim_start_matches("Option<").starts_with("Email") {
+            asserts.push("string::is::email($value)".to_string());
+        }
+        if validation.url || rust_type.trim_start_matches("Option<").starts_with("Url") {
+            asserts.push("string::is::url($value)".to_string());
+        }
+        if let Some(min_length) = validation.min_length {
+            asserts.push(format!("string::len($value) >= {}", min_length));
+        }
+        if let Some(max_length) = validation.max_length {
+            asserts.push(format!("string::len($value) <= {}", max_length));
+        }
+        if let Some(min) = &validation.min {
+            asserts.push(format!("$value >= {}", min));
+        }
+        if let Some(max) = &validation.max {
+            asserts.push(format!("$value <= {}", max));
+        }
+
+        let assert = match asserts.join(" AND ") {
+            assert if assert.is_empty() => None,
+            // Un champ optionnel absent ne doit pas déclencher ses validations, sauf `required`
+            assert if kind.starts_with("option<") && !validation.required => {
+                Some(format!("$value = NONE OR ({})", assert))
+            }
+            assert => Some(assert),
+        };
+
+        let default = validation
+            .default
+            .as_deref()
+            .map(|value| default_literal(&kind, value));
+
+        Self {
+            kind,
+            default,
+            assert,
+            ..Default::default()
+        }
+    }
+
+    /// `DEFINE FIELD`, avec `OVERWRITE` pour remplacer une définition existante
+    pub fn define(&self, table: &str, name: &str, overwrite: bool) -> String {
+        let mut sql = format!(
+            "DEFINE FIELD {}{} ON {} TYPE {}",
+            if overwrite { "OVERWRITE " } else { "" },
+            name,
+            table,
+            self.kind
+        );
+        if let Some(default) = &self.default {
+            sql.push_str(&format!(" DEFAULT {}", default));
+        }
+        if let Some(value) = &self.value {
+            sql.push_str(&format!(" VALUE {}", value));
+        }
+        if let Some(assert) = &self.assert {
+            sql.push_str(&format!(" ASSERT {}", assert));
+        }
+        sql.push(';');
+        sql
+    }
+
+    /// Relit une définition renvoyée par `INFO FOR TABLE`
+    fn parse(definition: &str) -> Option<Self> {
+        let regex = Regex::new(
+            r"^DEFINE FIELD \S+ ON \S+ (?:FLEXIBLE )?TYPE (.+?)(?: DEFAULT (.+?))?(?: READONLY)?(?: VALUE (.+?))?(?: ASSERT (.+?))?(?: PERMISSIONS .*)?$",
+        )
+        .unwrap();
+        let captures = regex.captures(definition)?;
+        let group = |i: usize| captures.get(i).map(|m| m.as_str().to_string());
+
+        Some(Self {
+            kind: group(1)?,
+            default: group(2),
+            value: group(3),
+            assert: group(4),
+        })
+    }
+}
+
+/// Définition d'un index SurrealDB
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexDef {
+    pub fields: Vec<String>,
+    #[serde(default)]
+    pub unique: bool,
+}
+
+impl IndexDef {
+    pub fn new(fields: &[&str], unique: bool) -> Self {
+        Self {
+            fields: fields.iter().map(|field| field.to_string()).collect(),
+            unique,
+        }
+    }
+
+    pub fn define(&self, table: &str, name: &str, overwrite: bool) -> String {
+        format!(
+            "DEFINE INDEX {}{} ON {} FIELDS {}{};",
+            if overwrite { "OVERWRITE " } else { "" },
+            name,
+            table,
+            self.fields.join(", "),
+            if self.unique { " UNIQUE" } else { "" }
+        )
+    }
+
+    fn parse(definition: &str) -> Option<Self> {
+        let regex = Regex::new(r"^DEFINE INDEX \S+ ON \S+ FIELDS (.+?)( UNIQUE)?(?: COMMENT .*)?$")
+            .unwrap();
+        let captures = regex.captures(definition)?;
+
+        Some(Self {
+            fields: captures[1]
+                .split(',')
+                .map(|f| f.trim().to_string())
+                .collect(),
+            unique: captures.get(2).is_some(),
+        })
+    }
+}
+
+/// Tables reliées par une table d'arêtes (`TYPE RELATION IN <from> OUT <to>`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EdgeDef {
+    pub from: String,
+    pub to: String,
+}
+
+impl EdgeDef {
+    /// Relit `TYPE RELATION IN <from> OUT <to>` dans une définition de table
+    fn parse(definition: &str) -> Option<Self> {
+        let regex = Regex::new(r"TYPE RELATION IN (\S+) OUT (\S+)").unwrap();
+        let captures = regex.captures(definition)?;
+        Some(Self {
+            from: captures[1].to_string(),
+            to: captures[2].to_string(),
+        })
+    }
+}
+
+/// Champs et index d'une table
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableSchema {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relation: Option<EdgeDef>,
+    pub fields: BTreeMap<String, FieldDef>,
+    pub indexes: BTreeMap<String, IndexDef>,
+}
+
+impl TableSchema {
+    /// Schéma attendu pour une entité : ses champs, ses timestamps et ses relations `belongsTo`
+    pub fn from_entity(table: &str, info: &EntityInfo) -> Self {
+        let mut schema = Self::default();
+
+        for (field_name, field_type, validation) in &info.fields {
+            schema.insert_entity_field(table, field_name, field_type, validation, info);
+        }
+
+        schema.fields.insert(
+            "created_at".to_string(),
+            FieldDef {
+                default: Some("time::now()".to_string()),
+                ..FieldDef::new("datetime")
+            },
+        );
+        schema.fields.insert(
+            "updated_at".to_string(),
+            FieldDef {
+                value: Some("time::now()".to_string()),
+                ..FieldDef::new("datetime")
+            },
+        );
+        schema
+            .fields
+            .insert("deleted_at".to_string(), FieldDef::new("option<datetime>"));
+
+        for field in TIMESTAMP_FIELDS {
+            let prefix = field.trim_end_matches("_at");
+            schema.indexes.insert(
+                format!("idx_{table}_{prefix}"),
+                IndexDef::new(&[field], false),
+            );
+        }
+
+        for relation in &info.relations {
+            if let RelationType::BelongsTo(target) = relation {
+                let target = module_name(target);
+                let field = format!("{}_id", target);
+                schema.fields.insert(
+                    field.clone(),
+                    FieldDef::new(&format!("record<{}>", table_name(&target))),
+                );
+                schema.indexes.insert(
+                    format!("idx_{table}_{target}"),
+                    IndexDef::new(&[&field], false),
+                );
+            }
+        }
+
+        schema
+    }
+
+    /// Ajoute un champ d'entité ; un objet imbriqué est un `object` dont chaque champ
+    /// est défini à part (`address.street`)
+    fn insert_entity_field(
+        &mut self,
+        table: &str,
+        name: &str,
+        field_type: &str,
+        validation: &FieldValidation,
+        info: &EntityInfo,
+    ) {
+        if let Some(enum_type) = find_enum(&info.enums, field_type) {
+            self.fields.insert(
+                name.to_string(),
+                FieldDef::from_enum_field(field_type, enum_type, validation),
+            );
+        } else if let Some(object) = find_object(&info.objects, field_type) {
+            let object_type = field_type.replace(&object.name, "Json");
+            self.fields.insert(
+                name.to_string(),
+                FieldDef::from_entity_field(&object_type, validation),
+            );
+            for (sub_name, sub_type, sub_validation) in &object.fields {
+                let path = format!("{name}.{sub_name}");
+                self.insert_entity_field(table, &path, sub_type, sub_validation, info);
+            }
+        } else {
+            self.fields.insert(
+                name.to_string(),
+                FieldDef::from_entity_field(field_type, validation),
+            );
+        }
+
+        if validation.unique {
+            self.indexes.insert(
+                format!("idx_{table}_{}_unique", name.replace('.', "_")),
+                IndexDef::new(&[name], true),
+            );
+        }
+    }
+
+    /// Table d'arêtes d'une relation `manyToMany`, une seule arête par couple d'enregistrements
+    pub fn edge(edge: &str, from: &str, to: &str) -> Self {
+        let mut schema = Self {
+            relation: Some(EdgeDef {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+            ..Default::default()
+        };
+        schema.fields.insert(
+            "created_at".to_string(),
+            FieldDef {
+                default: Some("time::now()".to_string()),
+                ..FieldDef::new("datetime")
+            },
+        );
+        schema.indexes.insert(
+            format!("idx_{edge}_unique"),
+            IndexDef::new(&["in", "out"], true),
+        );
+        schema
+    }
+
+    /// Tables d'arêtes des relations `manyToMany` d'une entité
+    pub fn edges_of(table: &str, info: &EntityInfo) -> Vec<(String, Self)> {
+        info.relations
+            .iter()
+            .filter_map(|relation| match relation {
+                RelationType::ManyToMany(target, edge) => {
+                    Some((edge.clone(), Self::edge(edge, table, &table_name(target))))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Instructions créant la table, ses champs puis ses index
+    pub fn define(&self, table: &str) -> Vec<String> {
+        let mut statements = vec![match &self.relation {
+            Some(edge) => format!(
+                "DEFINE TABLE {} TYPE RELATION IN {} OUT {} SCHEMAFULL;",
+                table, edge.from, edge.to
+            ),
+            None => format!("DEFINE TABLE {} SCHEMAFULL;", table),
+        }];
+        statements.extend(
+            self.fields
+                .iter()
+                .map(|(name, field)| field.define(table, name, false)),
+        );
+        statements.extend(
+            self.indexes
+                .iter()
+                .map(|(name, index)| index.define(table, name, false)),
+        );
+        statements
+    }
+}
+
+/// Schéma complet de la base, table par table
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Schema {
+    pub tables: BTreeMap<String, TableSchema>,
+}
+
+impl Schema {
+    /// Schéma décrit par les entités de `backend/src/entities`
+    pub fn from_entities(root: &Path) -> Result<Self, String> {
+        let entities_dir = root.join("backend/src/entities");
+        let entries = fs::read_dir(&entities_dir)
+            .map_err(|e| format!("Failed to read {}: {}", entities_dir.display(), e))?;
+
+        let mut infos = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if !file_name.ends_with(".rs")
+                || ["mod.rs", "base_entity.rs"].contains(&file_name.as_str())
+            {
+                continue;
+            }
+
+            let content = fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            infos.extend(EntityInfo::parse(&content));
+        }
+
+        Ok(Self::from_infos(&infos))
+    }
+
+    /// Schéma décrit par des entités déjà connues, sans relire leurs fichiers Rust
+    pub fn from_infos(infos: &[EntityInfo]) -> Self {
+        let mut schema = Self::default();
+        for info in infos {
+            let table = table_name(&info.name);
+            schema.tables.extend(TableSchema::edges_of(&table, info));
+            schema
+                .tables
+                .insert(table.clone(), TableSchema::from_entity(&table, info));
+        }
+        schema
+    }
+
+    /// Charge le snapshot du projet ; un projet sans snapshot part d'un schéma vide
+    pub fn load_snapshot(root: &Path) -> Result<Self, String> {
+        let path = root.join(SNAPSHOT_PATH);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", SNAPSHOT_PATH, e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Invalid {}: {}", SNAPSHOT_PATH, e))
+    }
+
+    pub fn save_snapshot(&self, root: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize schema snapshot: {}", e))?;
+        fs::write(root.join(SNAPSHOT_PATH), content + "\n")
+            .map_err(|e| format!("Failed to write {}: {}", SNAPSHOT_PATH, e))
+    }
+
+    /// Schéma réel d'une base, lu avec `INFO FOR DB` puis `INFO FOR TABLE`
+    pub async fn from_database(db: &Surreal<Any>) -> Result<Self, String> {
+        let info = info_for(db, "INFO FOR DB".to_string()).await?;
+        let tables = info["tables"]
+            .as_object()
+            .map(|tables| {
+                tables
+                    .iter()
+                    .map(|(name, definition)| {
+                        (name.clone(), definition.as_str().map(EdgeDef::parse))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let mut schema = Self::default();
+        // Les tables internes de la CLI (ledger, sauvegardes) ne font pas partie du schéma
+        for (table, relation) in tables
+            .into_iter()
+            .filter(|(t, _)| !t.starts_with("_nebulis"))
+        {
+            let info = info_for(db, format!("INFO FOR TABLE `{}`", table)).await?;
+            let mut table_schema = TableSchema {
+                relation: relation.flatten(),
+                ..Default::default()
+            };
+
+            for (name, definition) in info["fields"].as_object().into_iter().flatten() {
+                // Les sous-champs `tags[*]` et les champs `in`/`out` des arêtes sont créés par SurrealDB
+                if name.contains("[*]")
+                    || (table_schema.relation.is_some() && (name == "in" || name == "out"))
+                {
+                    continue;
+                }
+                if let Some(field) = definition.as_str().and_then(FieldDef::parse) {
+                    table_schema.fields.insert(name.clone(), field);
+                }
+            }
+            for (name, definition) in info["indexes"].as_object().into_iter().flatten() {
+                if let Some(index) = definition.as_str().and_then(IndexDef::parse) {
+                    table_schema.indexes.insert(name.clone(), index);
+                }
+            }
+
+            schema.tables.insert(table, table_schema);
+        }
+
+        Ok(schema)
+    }
+}
+
+async fn info_for(db: &Surreal<Any>, query: String) -> Result<serde_json::Value, String> {
+    let mut response = db
+        .query(&query)
+        .await
+        .map_err(|e| format!("Failed to run {}: {}", query, e))?;
+    let info: Option<serde_json::Value> = response
+        .take(0)
+        .map_err(|e| format!("Failed to run {}: {}", query, e))?;
+    Ok(info.unwrap_or_default())
+}
+
+/// Instructions faisant passer la base d'un schéma à l'autre, et leur inverse
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+    pub up: Vec<String>,
+    pub down: Vec<String>,
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        self.up.is_empty()
+    }
+}
+
+/// Compare deux schémas ; le `down` annule le `up` dans l'ordre inverse
+pub fn diff(from: &Schema, to: &Schema) -> SchemaDiff {
+    let mut steps: Vec<(String, String)> = Vec::new();
+
+    for (table, schema) in &to.tables {
+        let Some(previous) = from.tables.get(table) else {
+            let up = schema.define(table).join("\n");
+            steps.push((up, format!("REMOVE TABLE {};", table)));
+            continue;
+        };
+
+        for (name, index) in &previous.indexes {
+            if !schema.indexes.contains_key(name) {
+                steps.push((
+                    format!("REMOVE INDEX {} ON {};", name, table),
+                    index.define(table, name, false),
+                ));
+            }
+        }
+        for (name, field) in &previous.fields {
+            if !schema.fields.contains_key(name) {
+                steps.push((
+                    format!("REMOVE FIELD {} ON {};", name, table),
+                    field.define(table, name, false),
+                ));
+            }
+        }
+        for (name, field) in &schema.fields {
+            match previous.fields.get(name) {
+                None => steps.push((
+                    field.define(table, name, false),
+                    format!("REMOVE FIELD {} ON {};", name, table),
+                )),
+                Some(old) if old != field => steps.push((
+                    field.define(table, name, true),
+                    old.define(table, name, true),
+                )),
+                Some(_) => {}
+            }
+        }
+        for (name, index) in &schema.indexes {
+            match previous.indexes.get(name) {
+                None => steps.push((
+                    index.define(table, name, false),
+                    format!("REMOVE INDEX {} ON {};", name, table),
+                )),
+                Some(old) if old != index => steps.push((
+                    index.define(table, name, true),
+                    old.define(table, name, true),
+                )),
+                Some(_) => {}
+            }
+        }
+    }
+
+    for (table, schema) in &from.tables {
+        if !to.tables.contains_key(table) {
+            steps.push((
+                format!("REMOVE TABLE {};", table),
+                schema.define(table).join("\n"),
+            ));
+        }
+    }
+
+    SchemaDiff {
+        up: steps.iter().map(|(up, _)| up.clone()).collect(),
+        down: steps.into_iter().rev().map(|(_, down)| down).collect(),
+    }
+}
+
+/// Type SurrealQL correspondant à un type Rust d'entité
+pub fn surql_type(rust_type: &str) -> String {
+    let rust_type = rust_type.trim();
+    if let Some(inner) = generic_argument(rust_type, "Option") {
+        return format!("option<{}>", surql_type(inner));
+    }
+    if let Some(inner) = generic_argument(rust_type, "Vec") {
+        return format!("array<{}>", surql_type(inner));
+    }
+
+    match rust_type {
+        "String" | "&str" | "Email" | "Phone" | "Url" | "Slug" | "Password" => "string",
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "usize" | "isize" => "int",
+        "f32" | "f64" => "float",
+        "bool" => "bool",
+        "DateTime" | "DateTime<Utc>" | "NaiveDateTime" | "NaiveDate" => "datetime",
+        "Json" | "serde_json::Value" => "object",
+        "Money" | "Decimal" => "decimal",
+        "Uuid" => "uuid",
+        // Les enums générés sont stockés sous forme de chaîne (`PostStatus` -> `'draft'`)
+        _ => "string",
+    }
+    .to_string()
+}
+
+/// Littéral SurrealQL d'une valeur par défaut : brut pour les nombres et booléens, entre
+/// apostrophes sinon, comme le renvoie `INFO FOR TABLE`
+fn default_literal(kind: &str, value: &str) -> String {
+    let base = kind
+        .strip_prefix("option<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(kind);
+    match base {
+        "int" | "float" | "decimal" | "bool" => value.to_string(),
+        _ => format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'")),
+    }
+}
+
+/// Extrait `T` d'un type `Wrapper<T>`
+fn generic_argument<'a>(type_: &'a str, wrapper: &str) -> Option<&'a str> {
+    type_
+        .strip_prefix(wrapper)?
+        .strip_prefix('<')?
+        .strip_suffix('>')
+}
@@ -0,0 +1,125 @@
+// src/db/seeder.rs
+use super::transaction::{split_statements, Transaction};
+use super::DbConfig;
+use colored::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use surrealdb::engine::any::Any;
+use surrealdb::Surreal;
+
+/// Environnement utilisé quand `--env` n'est pas précisé
+pub const DEFAULT_ENV: &str = "development";
+
+/// Fichiers de seed d'un environnement, chacun trié par nom : les `database/seeds/*.surql` qui
+/// déclarent cet environnement sur leur première ligne (`-- env: development, test`, ou
+/// `-- env: *` pour tous), puis `database/seeds/<env>/*.surql`
+pub fn discover(root: &Path, env: &str) -> Result<Vec<PathBuf>, String> {
+    if env.is_empty()
+        || !env
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!("Invalid seed environment: {}", env));
+    }
+
+    let seeds_dir = root.join("database/seeds");
+    if !seeds_dir.exists() {
+        return Err("No database/seeds directory found".into());
+    }
+
+    let mut seeds = Vec::new();
+    for path in surql_files(&seeds_dir)? {
+        let sql = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let envs = declared_envs(&sql).ok_or_else(|| {
+            format!(
+                "{} must declare its environments on its first line (-- env: development, production), \
+                 or move to database/seeds/<env>/",
+                path.display()
+            )
+        })?;
+        if envs
+            .iter()
+            .any(|declared| declared == env || declared == "*")
+        {
+            seeds.push(path);
+        }
+    }
+
+    let env_dir = seeds_dir.join(env);
+    if env_dir.is_dir() {
+        seeds.extend(surql_files(&env_dir)?);
+    }
+
+    Ok(seeds)
+}
+
+/// Environnements déclarés par la première ligne d'un seed (`-- env: development, test`)
+fn declared_envs(sql: &str) -> Option<Vec<String>> {
+    let envs = sql
+        .lines()
+        .next()?
+        .trim()
+        .strip_prefix("--")?
+        .trim()
+        .strip_prefix("env:")?;
+    Some(envs.split(',').map(|env| env.trim().to_string()).collect())
+}
+
+/// Vérifie que `--env` correspond à la base visée : son `DB_ENV` s'il est défini, et jamais
+/// `production` sur une base locale qui ne s'est pas déclarée comme telle
+pub fn check_env(config: &DbConfig, env: &str) -> Result<(), String> {
+    match &config.env {
+        Some(db_env) if db_env != env => Err(format!(
+            "Refusing to run {} seeds: the database is declared as {} (DB_ENV)",
+            env, db_env
+        )),
+        None if env == "production" && config.is_local() => Err(format!(
+            "Refusing to run production seeds against the local database {} (set DB_ENV=production to confirm)",
+            config.endpoint()
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn surql_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+
+    let mut files = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "surql"))
+        .collect::<Vec<_>>();
+    files.sort();
+
+    Ok(files)
+}
+
+/// Exécute les seeds d'un environnement, chaque fichier dans sa propre transaction
+pub async fn run(db: &Surreal<Any>, root: &Path, env: &str) -> Result<Vec<String>, String> {
+    let seeds_dir = root.join("database/seeds");
+    let mut done = Vec::new();
+
+    for path in discover(root, env)? {
+        let name = path
+            .strip_prefix(&seeds_dir)
+            .unwrap_or(&path)
+            .display()
+            .to_string();
+        let sql = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+        let source = format!("Seed {}", name);
+        let mut tx = Transaction::new();
+        for (number, statement) in split_statements(&sql).iter().enumerate() {
+            tx.push(&source, number + 1, statement);
+        }
+        tx.commit(db).await?;
+
+        println!("  {} {}", "✓".green(), name);
+        done.push(name);
+    }
+
+    Ok(done)
+}
@@ -0,0 +1,183 @@
+// src/db/transaction.rs
+use surrealdb::engine::any::Any;
+use surrealdb::Surreal;
+
+/// Message renvoyé par SurrealDB pour les instructions annulées avec la transaction
+const NOT_EXECUTED: &str = "not executed due to a failed transaction";
+
+/// Instruction d'une transaction, rattachée au fichier qui l'a produite
+/// (ex. `Migration create_users`, `Seed users.surql`)
+#[derive(Debug, Clone)]
+struct Statement {
+    source: String,
+    number: Option<usize>,
+    sql: String,
+}
+
+/// Regroupe les instructions d'un ou plusieurs fichiers `.surql` dans un seul
+/// `BEGIN TRANSACTION; ... COMMIT TRANSACTION;`
+#[derive(Debug, Default)]
+pub struct Transaction {
+    statements: Vec<Statement>,
+    bindings: Vec<(String, String)>,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ajoute la `number`-ième instruction d'un fichier `.surql`
+    pub fn push(&mut self, source: &str, number: usize, sql: &str) {
+        self.statements.push(Statement {
+            source: source.to_string(),
+            number: Some(number),
+            sql: sql.to_string(),
+        });
+    }
+
+    /// Ajoute une instruction générée par la CLI (ledger, chronométrage)
+    pub fn push_internal(&mut self, source: &str, sql: String) {
+        self.statements.push(Statement {
+            source: source.to_string(),
+            number: None,
+            sql,
+        });
+    }
+
+    pub fn bind(&mut self, key: String, value: &str) {
+        self.bindings.push((key, value.to_string()));
+    }
+
+    fn to_query(&self) -> String {
+        let body = self
+            .statements
+            .iter()
+            .map(|statement| format!("{};", statement.sql))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!("BEGIN TRANSACTION;\n{}\nCOMMIT TRANSACTION;", body)
+    }
+
+    /// Exécute la transaction ; en cas d'erreur SurrealDB l'annule entièrement
+    /// et l'instruction fautive est indiquée dans le message d'erreur
+    pub async fn commit(self, db: &Surreal<Any>) -> Result<(), String> {
+        let mut query = db.query(self.to_query());
+        for binding in self.bindings.iter().cloned() {
+            query = query.bind(binding);
+        }
+
+        let mut response = query
+            .await
+            .map_err(|e| format!("Failed to run transaction: {}", e))?;
+
+        let mut errors = response
+            .take_errors()
+            .into_iter()
+            .map(|(index, error)| (index, error.to_string()))
+            .collect::<Vec<_>>();
+        errors.sort_by_key(|(index, _)| *index);
+
+        let failure = errors
+            .iter()
+            .find(|(_, error)| !error.contains(NOT_EXECUTED))
+            .or(errors.first());
+
+        let Some((index, error)) = failure else {
+            return Ok(());
+        };
+
+        let statement = self
+            .statements
+            .get(*index)
+            .ok_or_else(|| format!("Transaction cancelled: {}", error))?;
+
+        Err(match statement.number {
+            Some(number) => format!(
+                "{} failed at statement {}: {}\n    {}\nTransaction cancelled",
+                statement.source, number, error, statement.sql
+            ),
+            None => format!(
+                "{} could not be recorded: {}\nTransaction cancelled",
+                statement.source, error
+            ),
+        })
+    }
+}
+
+/// Découpe un script SurrealQL en instructions, en ignorant les commentaires
+/// et les `;` situés dans des chaînes ou des blocs `{ ... }`
+pub fn split_statements(sql: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut depth = 0usize;
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if let Some(q) = quote {
+            current.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '\'' | '"' | '`' => {
+                quote = Some(c);
+                current.push(c);
+            }
+            '-' | '/' if chars.peek() == Some(&c) => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '#' => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            '{' => {
+                depth += 1;
+                current.push(c);
+            }
+            '}' => {
+                depth = depth.saturating_sub(1);
+                current.push(c);
+            }
+            ';' if depth == 0 => {
+                if !current.trim().is_empty() {
+                    statements.push(current.trim().to_string());
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if !current.trim().is_empty() {
+        statements.push(current.trim().to_string());
+    }
+
+    statements
+}
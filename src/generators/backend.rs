@@ -1,6 +1,9 @@
 // src/generators/backend.rs
+use crate::generators::entity::ensure_base_entity;
+use crate::generators::resolver::{root_content, GRAPHQL_MOD_LINES};
 use crate::utils::fs as fs_utils;
 use std::fs;
+use std::path::Path;
 
 pub fn create_structure(project_name: &str) {
     let backend_path = format!("{}/backend", project_name);
@@ -16,6 +19,7 @@ fn create_directories(backend_path: &str) {
         "src/graphql/mutations",
         "src/graphql/queries",
         "src/graphql/types",
+        "src/graphql/resolvers",
         "src/graphql/scalars",
         "src/migrations",
         "src/entities",
@@ -28,6 +32,10 @@ fn create_directories(backend_path: &str) {
 }
 
 fn create_cargo_toml(path: &str, project_name: &str) {
+    // Le projet peut être créé ailleurs que dans le répertoire courant
+    let package_name = Path::new(project_name)
+        .file_name()
+        .map_or(project_name.into(), |name| name.to_string_lossy());
     let content = format!(
         r#"[package]
 name = "{}_backend"
@@ -36,7 +44,7 @@ edition = "2021"
 
 [dependencies]
 tokio = {{ version = "1.0", features = ["full"] }}
-axum = {{ version = "0.7", features = ["macros"] }}
+axum = {{ version = "0.8", features = ["macros"] }}
 async-graphql = {{ version = "7.0", features = ["chrono"] }}
 async-graphql-axum = "7.0"
 surrealdb = {{ version = "1.0.0" }}
@@ -49,8 +57,11 @@ tracing = "0.1"
 tracing-subscriber = {{ version = "0.3", features = ["env-filter"] }}
 thiserror = "1.0"
 chrono = {{ version = "0.4", features = ["serde"] }}
-validator = {{ version = "0.16", features = ["derive"] }}"#,
-        project_name
+validator = {{ version = "0.16", features = ["derive"] }}
+regex = "1"
+once_cell = "1"
+"#,
+        package_name
     );
 
     fs::write(format!("{}/Cargo.toml", path), content)
@@ -59,71 +70,82 @@ validator = {{ version = "0.16", features = ["derive"] }}"#,
 
 fn create_source_files(path: &str) {
     let main_rs = r#"mod db;
-mod graphql;
 mod entities;
+mod graphql;
 mod repositories;
 mod services;
 mod utils;
 
-use dotenv::dotenv;
+use async_graphql::{EmptySubscription, Schema};
+use async_graphql_axum::GraphQL;
+use axum::{routing::get, Router};
+use graphql::{MutationRoot, QueryRoot};
 
 #[tokio::main]
-async fn main() {
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize tracing
     tracing_subscriber::fmt::init();
 
-    // Load environment variables
-    dotenv().ok();
-
-    // Run server
-    server::run()
+    // Load environment variables (the project .env is in the parent directory)
+    dotenvy::dotenv().ok();
+
+    // Build our GraphQL schema, resolvers reading the database from the context
+    let db = db::connect().await?;
+    let schema = Schema::build(
+        QueryRoot::default(),
+        MutationRoot::default(),
+        EmptySubscription,
+    )
+    .data(db)
+    .finish();
+
+    // Build our application with routes
+    let app = Router::new()
+        .route("/", get(|| async { "Nebulis Backend API" }))
+        .route_service("/graphql", GraphQL::new(schema));
+
+    // Run our application
+    let host = std::env::var("SERVER_HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
+    let port = std::env::var("SERVER_PORT").unwrap_or_else(|_| "8080".to_string());
+    let listener = tokio::net::TcpListener::bind(format!("{}:{}", host, port)).await?;
+    tracing::info!("Server running on http://{}:{}", host, port);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+"#;
+
+    let db_rs = r#"use std::env;
+use surrealdb::engine::remote::ws::{Client, Ws};
+use surrealdb::opt::auth::Root;
+use surrealdb::Surreal;
+
+/// Connexion SurrealDB décrite par les variables DB_* du .env
+pub async fn connect() -> surrealdb::Result<Surreal<Client>> {
+    let var = |key: &str, default: &str| env::var(key).unwrap_or_else(|_| default.to_string());
+    let database = var("DB_NAME", "nebulis");
+
+    let db = Surreal::new::<Ws>(format!(
+        "{}:{}",
+        var("DB_HOST", "localhost"),
+        var("DB_PORT", "8000")
+    ))
     .await?;
-}"#;
-
-    let server_rs = r#"
-use async_graphql::{EmptySubscription, Schema};
-use async_graphql_axum::GraphQLHandler;
-use axum::{
-    routing::get,
-    Router,
-};
-
-struct Server;
-
-impl Server {
-    async fn run() {
-        //Environment variables
-        let host = env::var("SERVER_HOST")?;
-        let port = env::var("SERVER_PORT")?.parse::<u16>()?;
-
-        // Build our GraphQL schema
-        let schema = Schema::new(
-            graphql::QueryRoot::default(),
-            graphql::MutationRoot::default(),
-            EmptySubscription,
-        );
-
-        // Build our application with routes
-        let app = Router::new()
-            .route("/", get(|| async { "Nebulis Backend API" }))
-            .route("/graphql",
-                get(GraphQLHandler::new(schema.clone()))
-                .post(GraphQLHandler::new(schema))
-            );
-
-        // Run our application
-        let listener = tokio::net::TcpListener::bind("0.0.0.0:3000")
-            .await
-            .unwrap();
-        println!("Server running on http://localhost:3000");
-        axum::serve(listener, app).await?;
-    }
-}"#;
+    db.signin(Root {
+        username: &var("DB_USER", "root"),
+        password: &var("DB_PASSWORD", "root"),
+    })
+    .await?;
+    db.use_ns(var("DB_NAMESPACE", &database))
+        .use_db(database)
+        .await?;
+    Ok(db)
+}
+"#;
 
     fs::write(format!("{}/src/main.rs", path), main_rs)
         .unwrap_or_else(|_| panic!("Failed to create main.rs"));
-    fs::write(format!("{}/src/server.rs", path), server_rs)
-        .unwrap_or_else(|_| panic!("Failed to create server.rs"));
+    fs::write(format!("{}/src/db.rs", path), db_rs)
+        .unwrap_or_else(|_| panic!("Failed to create db.rs"));
 
     create_mod_files(path);
 }
@@ -131,7 +153,7 @@ impl Server {
 fn create_mod_files(base_path: &str) {
     let mod_paths = [
         "src/migrations",
-        "src/graphql",
+        "src/graphql/resolvers",
         "src/entities",
         "src/repositories",
         "src/services",
@@ -145,4 +167,21 @@ fn create_mod_files(base_path: &str) {
         )
         .unwrap_or_else(|_| panic!("Failed to create mod.rs in {}", path));
     }
+
+    // `graphql::QueryRoot` et `graphql::MutationRoot`, complétés par `generate resolver`
+    fs::write(
+        format!("{}/src/graphql/mod.rs", base_path),
+        format!(
+            "// Generated by Nebulis CLI\n{}\n",
+            GRAPHQL_MOD_LINES.join("\n")
+        ),
+    )
+    .unwrap_or_else(|_| panic!("Failed to create mod.rs in src/graphql"));
+    fs::write(
+        format!("{}/src/graphql/root.rs", base_path),
+        root_content(&[]),
+    )
+    .unwrap_or_else(|_| panic!("Failed to create src/graphql/root.rs"));
+
+    ensure_base_entity(&format!("{}/src", base_path)).unwrap_or_else(|e| panic!("{}", e));
 }
@@ -1,49 +1,377 @@
 // src/generators/entity
+use crate::generators::{entity_builder, fields, scalar, service};
 use colored::*;
 use convert_case::{Case, Casing};
+use regex::Regex;
 use std::fs;
 use std::path::Path;
 
 /// Définit les types de relations possibles entre les modèles
-#[derive(Debug, Clone)]
-enum RelationType {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelationType {
     HasOne(String),
     HasMany(String),
     BelongsTo(String),
+    /// Relation graphe : cible et table d'arêtes (`manyToMany->Tag via tagged`)
+    ManyToMany(String, String),
 }
 
 /// Configuration de validation pour un champ
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct FieldValidation {
-    required: bool,
-    min_length: Option<usize>,
-    max_length: Option<usize>,
-    pattern: Option<String>,
-    min: Option<String>,
-    max: Option<String>,
-    unique: bool,
-    email: bool,
-    url: bool,
-}
-
-impl Default for FieldValidation {
-    fn default() -> Self {
-        Self {
-            required: false,
-            min_length: None,
-            max_length: None,
-            pattern: None,
-            min: None,
-            max: None,
-            unique: false,
-            email: false,
-            url: false,
+    pub required: bool,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<String>,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    pub unique: bool,
+    pub email: bool,
+    pub url: bool,
+    /// Valeur par défaut (`views:i32=0`), conservée dans l'entité par un commentaire `/// Default:`
+    pub default: Option<String>,
+}
+
+/// Champ d'entité : nom, type Rust et validations
+pub type Field = (String, String, FieldValidation);
+
+/// Objet imbriqué dans une entité (`address:Address{street:String,city:String}`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObjectType {
+    pub name: String,
+    pub fields: Vec<Field>,
+}
+
+/// Objet imbriqué correspondant à un type de champ (`Address` ou `Option<Address>`)
+pub fn find_object<'a>(objects: &'a [ObjectType], type_: &str) -> Option<&'a ObjectType> {
+    let name = generic_argument(type_, "Option").unwrap_or_else(|| type_.to_string());
+    objects.iter().find(|object| object.name == name)
+}
+
+/// Enum déclaré par un champ (`status:enum(Draft,Published)`), nommé `<Entité><Champ>`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnumType {
+    pub name: String,
+    pub variants: Vec<String>,
+}
+
+impl EnumType {
+    /// Valeurs stockées en base, une par variante (`Draft` -> `draft`)
+    pub fn values(&self) -> Vec<String> {
+        self.variants
+            .iter()
+            .map(|variant| variant.to_case(Case::Snake))
+            .collect()
+    }
+}
+
+/// Enum correspondant à un type de champ (`PostStatus` ou `Option<PostStatus>`)
+pub fn find_enum<'a>(enums: &'a [EnumType], type_: &str) -> Option<&'a EnumType> {
+    let name = generic_argument(type_, "Option").unwrap_or_else(|| type_.to_string());
+    enums.iter().find(|enum_type| enum_type.name == name)
+}
+
+/// Champs gérés par le template, ignorés lors de la relecture d'une entité
+const SYSTEM_FIELDS: [&str; 4] = ["id", "created_at", "updated_at", "deleted_at"];
+
+/// Informations d'une entité relues depuis son fichier généré
+#[derive(Debug, Default)]
+pub struct EntityInfo {
+    pub name: String,
+    pub fields: Vec<Field>,
+    pub relations: Vec<RelationType>,
+    pub objects: Vec<ObjectType>,
+    pub enums: Vec<EnumType>,
+}
+
+impl EntityInfo {
+    /// Charge `backend/src/entities/<name>.rs`
+    pub fn load(name: &str) -> Result<Self, String> {
+        Self::parse_from_file(&entity_path(name))
+    }
+
+    pub fn parse_from_file(entity_path: &str) -> Result<Self, String> {
+        let content = fs::read_to_string(entity_path)
+            .map_err(|e| format!("Could not read entity file: {}", e))?;
+        Self::parse(&content).ok_or_else(|| format!("No entity struct found in {}", entity_path))
+    }
+
+    /// Parse la première structure du fichier, les champs après `// Relations` étant des relations
+    pub fn parse(content: &str) -> Option<Self> {
+        let struct_regex = Regex::new(r"(?s)pub struct (\w+) \{(.*?)\n\}").unwrap();
+        let captures = struct_regex.captures(content)?;
+        let body = captures.get(2)?.as_str();
+
+        let mut info = EntityInfo {
+            name: captures[1].to_string(),
+            ..Default::default()
+        };
+        let mut validation = FieldValidation::default();
+        let mut in_relations = false;
+        let mut last_id_field: Option<String> = None;
+        let mut pending_edge: Option<String> = None;
+        let edge_regex = Regex::new(r"^/// Graph edge ->(\w+)->\w+$").unwrap();
+
+        for line in body.lines().map(str::trim) {
+            if line == "// Relations" {
+                in_relations = true;
+                continue;
+            }
+
+            if let Some(captures) = edge_regex.captures(line) {
+                pending_edge = Some(captures[1].to_string());
+                continue;
+            }
+
+            if parse_field_attribute(&mut validation, line) {
+                continue;
+            }
+
+            let Some((name, type_)) = line
+                .strip_prefix("pub ")
+                .and_then(|field| field.split_once(':'))
+            else {
+                continue;
+            };
+            let name = name.trim();
+            let type_ = type_.trim().trim_end_matches(',').trim();
+            let field_validation = std::mem::take(&mut validation);
+
+            if !in_relations {
+                if !SYSTEM_FIELDS.contains(&name) {
+                    info.fields
+                        .push((name.to_string(), type_.to_string(), field_validation));
+                }
+                continue;
+            }
+
+            // Clé étrangère d'un belongsTo : `Option<Thing>`, ou `ID` dans les entités plus anciennes
+            if type_ == "Option<Thing>" || type_ == "ID" {
+                last_id_field = name.strip_suffix("_id").map(str::to_string);
+            } else if let Some(target) = generic_argument(type_, "Option") {
+                if last_id_field.take().as_deref() == Some(name) {
+                    info.relations.push(RelationType::BelongsTo(target));
+                } else {
+                    info.relations.push(RelationType::HasOne(target));
+                }
+            } else if let Some(target) = generic_argument(type_, "Vec") {
+                match pending_edge.take() {
+                    Some(edge) => info.relations.push(RelationType::ManyToMany(target, edge)),
+                    None => info.relations.push(RelationType::HasMany(target)),
+                }
+            }
+        }
+
+        info.objects = parse_objects(content, &info.fields);
+        info.enums = parse_enums(content, &info);
+        Some(info)
+    }
+}
+
+/// Relit les structures des objets imbriqués référencés par des champs, récursivement
+fn parse_objects(content: &str, fields: &[Field]) -> Vec<ObjectType> {
+    let mut objects: Vec<ObjectType> = Vec::new();
+    let mut pending = fields
+        .iter()
+        .map(|(_, type_, _)| generic_argument(type_, "Option").unwrap_or(type_.clone()))
+        .collect::<Vec<_>>();
+
+    while let Some(name) = pending.pop() {
+        if objects.iter().any(|object| object.name == name) {
+            continue;
+        }
+        let struct_regex = Regex::new(&format!(
+            r"(?s)pub struct {} \{{(.*?)\n\}}",
+            regex::escape(&name)
+        ))
+        .unwrap();
+        let Some(captures) = struct_regex.captures(content) else {
+            continue;
+        };
+
+        let mut object = ObjectType {
+            name,
+            fields: Vec::new(),
+        };
+        let mut validation = FieldValidation::default();
+        for line in captures[1].lines().map(str::trim) {
+            if parse_field_attribute(&mut validation, line) {
+                continue;
+            }
+            if let Some((field_name, type_)) = line
+                .strip_prefix("pub ")
+                .and_then(|field| field.split_once(':'))
+            {
+                let type_ = type_.trim().trim_end_matches(',').trim().to_string();
+                pending.push(generic_argument(&type_, "Option").unwrap_or(type_.clone()));
+                object.fields.push((
+                    field_name.trim().to_string(),
+                    type_,
+                    std::mem::take(&mut validation),
+                ));
+            }
+        }
+        objects.push(object);
+    }
+
+    objects
+}
+
+/// Relit les enums utilisés par les champs de l'entité et de ses objets imbriqués
+fn parse_enums(content: &str, info: &EntityInfo) -> Vec<EnumType> {
+    let mut enums: Vec<EnumType> = Vec::new();
+    let fields = info
+        .fields
+        .iter()
+        .chain(info.objects.iter().flat_map(|object| object.fields.iter()));
+
+    for (_, type_, _) in fields {
+        let name = generic_argument(type_, "Option").unwrap_or(type_.clone());
+        if enums.iter().any(|enum_type| enum_type.name == name) {
+            continue;
+        }
+        let enum_regex = Regex::new(&format!(
+            r"(?s)pub enum {} \{{(.*?)\n\}}",
+            regex::escape(&name)
+        ))
+        .unwrap();
+        let Some(captures) = enum_regex.captures(content) else {
+            continue;
+        };
+
+        let variants = captures[1]
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with("#[") && !line.starts_with("//"))
+            .map(|line| line.trim_end_matches(',').to_string())
+            .collect();
+        enums.push(EnumType { name, variants });
+    }
+
+    enums
+}
+
+/// Extrait `T` d'un type `Wrapper<T>`
+pub fn generic_argument(type_: &str, wrapper: &str) -> Option<String> {
+    type_
+        .strip_prefix(wrapper)?
+        .strip_prefix('<')?
+        .strip_suffix('>')
+        .map(str::to_string)
+}
+
+/// Découpe une liste `a, b(c, d)` sur les virgules de premier niveau
+fn split_top_level(input: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(input[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(input[start..].trim());
+
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
+
+/// Relit une ligne `#[validate(...)]` ou un commentaire (`/// Default:`, `/// Required`...)
+/// précédant un champ
+fn parse_field_attribute(validation: &mut FieldValidation, line: &str) -> bool {
+    if let Some(default) = line.strip_prefix("/// Default: ") {
+        validation.default = Some(default.to_string());
+        return true;
+    }
+    if let Some(pattern) = line.strip_prefix("/// Pattern: ") {
+        validation.pattern = Some(pattern.to_string());
+        return true;
+    }
+    match line {
+        "/// Required" => validation.required = true,
+        "/// Unique" => validation.unique = true,
+        "#[validate]" => {}
+        _ => {
+            let Some(rules) = line.strip_prefix("/// Validate: ").or_else(|| {
+                line.strip_prefix("#[validate(")
+                    .and_then(|rules| rules.strip_suffix(")]"))
+            }) else {
+                return false;
+            };
+            for rule in split_top_level(rules) {
+                parse_validation_attribute(validation, rule);
+            }
+        }
+    }
+    true
+}
+
+/// Relit une règle `#[validate(...)]` telle qu'émise par `generate_field_with_validation`
+fn parse_validation_attribute(validation: &mut FieldValidation, rule: &str) {
+    let (kind, args) = match rule.split_once('(') {
+        Some((kind, args)) => (kind.trim(), args.trim_end_matches(')')),
+        None => (rule.trim(), ""),
+    };
+    let arg = |key: &str| {
+        split_top_level(args)
+            .into_iter()
+            .filter_map(|arg| arg.split_once('='))
+            .find(|(name, _)| name.trim() == key)
+            .map(|(_, value)| value.trim().trim_matches('"').to_string())
+    };
+
+    match kind {
+        "required" | "required_nested" => validation.required = true,
+        "email" => validation.email = true,
+        "url" => validation.url = true,
+        "length" => {
+            validation.min_length = arg("min")
+                .and_then(|v| v.parse().ok())
+                .or(validation.min_length);
+            validation.max_length = arg("max")
+                .and_then(|v| v.parse().ok())
+                .or(validation.max_length);
         }
+        "range" => {
+            validation.min = arg("min").or(validation.min.take());
+            validation.max = arg("max").or(validation.max.take());
+        }
+        // Le motif est relu depuis `/// Pattern:`, l'attribut ne nommant que sa regex statique
+        "regex" => {}
+        _ if kind.contains("validate_unique") => validation.unique = true,
+        _ => {}
+    }
+}
+
+/// Nom du module Rust d'une entité, partagé par l'entité, son repository, son service et
+/// son resolver (`BlogPost` -> `blog_post`)
+pub fn module_name(entity: &str) -> String {
+    entity.to_case(Case::Snake)
+}
+
+/// Fichier de l'entité (`backend/src/entities/blog_post.rs`)
+pub fn entity_path(entity: &str) -> String {
+    format!("backend/src/entities/{}.rs", module_name(entity))
+}
+
+/// Nom de la table SurrealDB d'une entité (`User` -> `users`)
+pub fn table_name(entity: &str) -> String {
+    let name = entity.to_case(Case::Snake);
+    if name.ends_with('s') {
+        name
+    } else {
+        format!("{}s", name)
     }
 }
 
 /// Liste des types valides pour les champs du modèle
-const VALID_TYPES: [&str; 25] = [
+pub const VALID_TYPES: [&str; 24] = [
     // Types de base
     "String",
     "i32",
@@ -51,6 +379,7 @@ const VALID_TYPES: [&str; 25] = [
     "f32",
     "f64",
     "bool",
+    "DateTime",
     "DateTime<Utc>",
     "Vec<String>",
     "Option<String>",
@@ -70,22 +399,18 @@ const VALID_TYPES: [&str; 25] = [
     "HasOne",
     "HasMany",
     "BelongsTo",
-    // Types enum
-    "Status",
-    "Role",
 ];
 
 /// Template pour la génération des modèles
 const ENTITY_TEMPLATE: &str = r#"use serde::{Deserialize, Serialize};
-use async_graphql::{{SimpleObject, InputObject, Enum}};
+use async_graphql::{SimpleObject, InputObject, Enum};
 use validator::Validate;
 use chrono::{DateTime, Utc};
-use crate::entities::base_entity::BaseEntity;
+use crate::entities::base_entity::{BaseEntity, SortOrder};
 {imports}
-
+{patterns}
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct {name} {
+{object_attributes}pub struct {name} {
     #[serde(flatten)]
     base: BaseEntity,
 
@@ -121,15 +446,13 @@ impl {name} {
         self.deleted_at = None;
     }
 }
-
+{nested_types}
 #[derive(InputObject, Validate)]
-#[serde(rename_all = "camelCase")]
 pub struct {name}Input {
 {input_fields}
 }
 
-#[derive(InputObject)]
-#[serde(rename_all = "camelCase")]
+#[derive(InputObject, Default)]
 pub struct {name}Filter {
     pub include_deleted: Option<bool>,
     pub from_date: Option<DateTime<Utc>>,
@@ -153,107 +476,169 @@ pub struct {name}Sort {
     pub order: SortOrder,
 }
 
-#[derive(InputObject)]
+#[derive(InputObject, Default)]
 pub struct {name}Pagination {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
     pub cursor: Option<String>,
+}{graph_resolvers}
+"#;
+
+/// Champs communs à toutes les entités (`backend/src/entities/base_entity.rs`), et ordre de
+/// tri partagé par leurs requêtes de liste
+const BASE_ENTITY_TEMPLATE: &str = r#"// Generated by Nebulis CLI
+use async_graphql::{ComplexObject, Enum, SimpleObject, ID};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+/// Identifiant SurrealDB et horodatage, aplatis dans chaque entité
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+#[graphql(complex)]
+pub struct BaseEntity {
+    #[graphql(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Thing>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[ComplexObject]
+impl BaseEntity {
+    /// Identifiant sans le nom de la table (`articles:abc` -> `abc`)
+    async fn id(&self) -> Option<ID> {
+        self.id.as_ref().map(|id| ID(id.id.to_raw()))
+    }
+}
+
+impl BaseEntity {
+    pub fn new() -> Self {
+        let now = Utc::now();
+        Self {
+            id: None,
+            created_at: Some(now),
+            updated_at: Some(now),
+        }
+    }
+
+    /// Clé de l'enregistrement, attendue par `type::thing('<table>', $id)`
+    pub fn key(&self) -> String {
+        self.id
+            .as_ref()
+            .map(|id| id.id.to_raw())
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
 pub enum SortOrder {
     Asc,
     Desc,
-}"#;
+}
+"#;
 
 // src/generators/entity (suite)
 
 /// Point d'entrée principal pour la génération d'un modèle
-pub fn execute(name: &str, fields: &[String]) -> Result<(), String> {
+///
+/// Sans `--fields`, l'entité est construite de manière interactive, sauf avec `--no-interactive`
+pub fn execute(name: &str, fields: &[String], interactive: bool) -> Result<(), String> {
     println!("{} {}", "Generating entity:".blue(), name);
 
     if !Path::new("backend").exists() {
         return Err("Not in a Nebulis project directory".into());
     }
 
-    let (fields, relations) = parse_fields_and_relations(fields)?;
+    let info = if fields.is_empty() && interactive {
+        let stdin = std::io::stdin();
+        match entity_builder::build(name, &mut stdin.lock(), &mut std::io::stdout())? {
+            Some(info) => info,
+            None => {
+                println!("{}", "Entity generation cancelled".yellow());
+                return Ok(());
+            }
+        }
+    } else {
+        fields::parse(name, fields)?
+    };
+    generate(&info)
+}
 
-    let entity_path = format!("backend/src/entities/{}.rs", name.to_lowercase());
-    let entity_content = generate_entity_content(name, &fields, &relations)?;
+/// Structure principale de l'entité telle qu'elle sera générée
+pub fn preview(info: &EntityInfo) -> Result<String, String> {
+    let content = generate_entity_content(info)?;
+    let start = content
+        .find("#[derive(")
+        .ok_or("Generated entity has no struct")?;
+    let end = content[start..]
+        .find("\n}\n")
+        .map_or(content.len(), |end| start + end + 2);
+    Ok(content[start..end].to_string())
+}
+
+/// Écrit l'entité décrite par `info` et ses couches (repository, service, types frontend)
+pub fn generate(info: &EntityInfo) -> Result<(), String> {
+    let name = info.name.as_str();
+    let entity_path = entity_path(name);
+    ensure_base_entity("backend/src")?;
+    let scalar_paths = scalar::ensure_scalars("backend/src", &used_scalars(info))?;
+    let entity_content = generate_entity_content(info)?;
     fs::write(&entity_path, entity_content)
         .map_err(|e| format!("Failed to write entity file: {}", e))?;
 
-    update_entities_mod(name, &relations)?;
-    update_graphql_mod(name)?;
+    let layer_paths = service::generate_layers(info)?;
+    let repository_path = generate_relations_repository(name, &info.relations)?;
+    let frontend_types_path = generate_frontend_types(info)?;
+
+    update_entities_mod(name)?;
 
     println!("{} Generated files:", "✓".green());
     println!("  - {}", entity_path);
+    let optional_paths = [repository_path, frontend_types_path].into_iter().flatten();
+    for path in layer_paths
+        .into_iter()
+        .chain(optional_paths)
+        .chain(scalar_paths)
+    {
+        println!("  - {}", path);
+    }
     Ok(())
 }
 
-/// Parse les champs et les relations à partir des définitions fournies
-/// Parse les champs et les relations à partir des définitions fournies
-fn parse_fields_and_relations(
-    fields: &[String],
-) -> Result<(Vec<(String, String, FieldValidation)>, Vec<RelationType>), String> {
-    let mut parsed_fields = Vec::new();
-    let mut relations = Vec::new();
-
-    // Séparer la chaîne en champs individuels
-    for field_group in fields {
-        let field_definitions: Vec<&str> = field_group.split(',').collect();
-
-        for field_def in field_definitions {
-            if field_def.contains("->") {
-                let parts: Vec<&str> = field_def.split("->").collect();
-                if parts.len() != 2 {
-                    return Err(format!("Invalid relation format: {}", field_def));
-                }
-
-                let relation_type = match parts[0].trim() {
-                    "hasOne" => RelationType::HasOne(parts[1].trim().to_string()),
-                    "hasMany" => RelationType::HasMany(parts[1].trim().to_string()),
-                    "belongsTo" => RelationType::BelongsTo(parts[1].trim().to_string()),
-                    _ => return Err(format!("Invalid relation type: {}", parts[0])),
-                };
-                relations.push(relation_type);
-            } else {
-                let field_parts: Vec<&str> = field_def.split(':').collect();
-                if field_parts.len() != 2 {
-                    return Err(format!("Invalid field format: {}. Expected format: name:type|validation1 validation2", field_def));
-                }
-
-                let name = field_parts[0].trim().to_string();
-                let type_and_validations: Vec<&str> = field_parts[1].split('|').collect();
-
-                if type_and_validations.is_empty() {
-                    return Err(format!("Missing type for field: {}", name));
-                }
-
-                let field_type = type_and_validations[0].trim().to_string();
-                validate_field_type(&field_type)?;
-
-                let mut validation = FieldValidation::default();
-                if type_and_validations.len() > 1 {
-                    let validation_rules = type_and_validations[1]
-                        .split(|c| c == ' ')
-                        .map(|s| s.trim())
-                        .filter(|s| !s.is_empty())
-                        .collect::<Vec<_>>();
-
-                    parse_validations(&mut validation, &validation_rules)?;
-                }
-
-                parsed_fields.push((name, field_type, validation));
-            }
+/// Déclare l'enum `<Entité><Champ>` à partir de ses variantes `Draft,Published`
+pub fn parse_enum(
+    field_name: &str,
+    variants: &str,
+    info: &mut EntityInfo,
+) -> Result<String, String> {
+    let enum_name = format!("{}{}", info.name, field_name.to_case(Case::Pascal));
+    let variants = variants
+        .split(',')
+        .map(|variant| variant.trim().to_case(Case::Pascal))
+        .filter(|variant| !variant.is_empty())
+        .collect::<Vec<_>>();
+
+    if variants.is_empty() {
+        return Err(format!(
+            "Enum {} must declare at least one variant",
+            enum_name
+        ));
+    }
+    for (i, variant) in variants.iter().enumerate() {
+        if variants[..i].contains(variant) {
+            return Err(format!("Enum {} declares {} twice", enum_name, variant));
         }
     }
 
-    Ok((parsed_fields, relations))
+    info.enums.push(EnumType {
+        name: enum_name.clone(),
+        variants,
+    });
+    Ok(enum_name)
 }
 
 /// Valide le type d'un champ
-fn parse_validations(validation: &mut FieldValidation, rules: &[&str]) -> Result<(), String> {
+pub fn parse_validations(validation: &mut FieldValidation, rules: &[&str]) -> Result<(), String> {
     for rule in rules {
         let rule = rule.trim();
         if rule.is_empty() {
@@ -320,44 +705,12 @@ fn parse_validations(validation: &mut FieldValidation, rules: &[&str]) -> Result
     Ok(())
 }
 /// Valide le type d'un champ
-fn validate_field_type(field_type: &str) -> Result<(), String> {
-    let valid_types = [
-        // Types de base
-        "String",
-        "i32",
-        "i64",
-        "f32",
-        "f64",
-        "bool",
-        "DateTime",
-        "Vec<String>",
-        "Option<String>",
-        "u32",
-        "u64",
-        "usize",
-        // Types personnalisés
-        "Email",
-        "Phone",
-        "Url",
-        "Password",
-        "Json",
-        "Money",
-        "Uuid",
-        "Slug",
-        // Types relationnels
-        "HasOne",
-        "HasMany",
-        "BelongsTo",
-        // Types enum
-        "Status",
-        "Role",
-    ];
-
-    if !valid_types.contains(&field_type) {
+pub fn validate_field_type(field_type: &str) -> Result<(), String> {
+    if !VALID_TYPES.contains(&field_type) {
         return Err(format!(
             "Invalid type: {}. Valid types are: {}",
             field_type,
-            valid_types.join(", ")
+            VALID_TYPES.join(", ")
         ));
     }
     Ok(())
@@ -366,36 +719,63 @@ fn validate_field_type(field_type: &str) -> Result<(), String> {
 // src/generators/entity (suite et fin)
 
 /// Génère le contenu du fichier modèle
-fn generate_entity_content(
-    name: &str,
-    fields: &[(String, String, FieldValidation)],
-    relations: &[RelationType],
-) -> Result<String, String> {
-    let struct_name = name.to_case(Case::Pascal);
+fn generate_entity_content(info: &EntityInfo) -> Result<String, String> {
+    let struct_name = info.name.to_case(Case::Pascal);
+    let (fields, relations, objects) = (&info.fields, &info.relations, &info.objects);
+
+    // Les relations sont des champs de la structure, nommés d'après leur cible
+    for relation in relations {
+        let (target, names) = match relation {
+            RelationType::HasOne(target) | RelationType::HasMany(target) => {
+                (target, vec![module_name(target)])
+            }
+            RelationType::BelongsTo(target) => (
+                target,
+                vec![module_name(target), format!("{}_id", module_name(target))],
+            ),
+            RelationType::ManyToMany(target, _) => (target, vec![table_name(target)]),
+        };
+        if let Some((name, _, _)) = fields.iter().find(|(name, _, _)| names.contains(name)) {
+            return Err(format!(
+                "Field {} conflicts with the relation to {}",
+                name, target
+            ));
+        }
+    }
 
-    // Génère les imports pour les relations
-    let imports = generate_relation_imports(relations);
+    // Génère les imports pour les relations et les scalaires
+    let imports = generate_imports(&struct_name, info);
 
     // Génère les champs avec leurs validations
     let fields_def = fields
         .iter()
-        .map(|(name, type_, validation)| generate_field_with_validation(name, type_, validation))
+        .map(|field| generate_field_with_validation(&struct_name, field, info, true) + ",")
         .collect::<Vec<_>>()
-        .join(",\n\n");
+        .join("\n\n");
 
     // Génère les relations
     let relations_def = relations
         .iter()
-        .map(generate_relation_field)
+        .map(|relation| generate_relation_field(relation) + ",")
         .collect::<Vec<_>>()
-        .join(",\n");
+        .join("\n");
 
-    // Génère les initialisations de champs
+    // Génère les initialisations de champs, à partir de leur valeur par défaut
     let field_inits = fields
         .iter()
-        .map(|(name, _, _)| format!("            {}: {}::default()", name, name))
+        .map(|(name, type_, validation)| {
+            format!(
+                "            {}: {}",
+                name,
+                default_expr(type_, validation.default.as_deref(), info)
+            )
+        })
         .collect::<Vec<_>>()
         .join(",\n");
+    let field_inits = match (field_inits.is_empty(), relations.is_empty()) {
+        (false, false) => format!("{},", field_inits),
+        _ => field_inits,
+    };
 
     // Génère les initialisations de relations
     let relation_inits = relations
@@ -404,15 +784,33 @@ fn generate_entity_content(
         .collect::<Vec<_>>()
         .join(",\n");
 
-    // Génère les champs d'input
+    // Génère les champs d'input, suivis des clés étrangères `belongsTo`
     let input_fields = fields
         .iter()
-        .map(|(name, type_, validation)| generate_input_field(name, type_, validation))
+        .map(|field| generate_input_field(&struct_name, field, info))
+        .chain(relations.iter().filter_map(|relation| match relation {
+            RelationType::BelongsTo(target) => {
+                Some(format!("    pub {}_id: ID", module_name(target)))
+            }
+            _ => None,
+        }))
         .collect::<Vec<_>>()
         .join(",\n");
 
-    // Génère les champs de filtre
-    let filter_fields = generate_filter_fields(fields);
+    // Génère les champs de filtre, suivis des filtres de relation
+    let mut filter_fields = generate_filter_fields(fields, info);
+    if !filter_fields.is_empty() {
+        filter_fields.push(',');
+    }
+
+    // Génère les enums, puis les objets imbriqués et leurs filtres
+    let nested_types = info
+        .enums
+        .iter()
+        .map(generate_enum)
+        .chain(objects.iter().map(|object| generate_object(object, info)))
+        .collect::<Vec<_>>()
+        .join("");
 
     // Génère les filtres de relation
     let relation_filters = generate_relation_filters(relations);
@@ -420,17 +818,27 @@ fn generate_entity_content(
     // Génère les champs de tri
     let sort_fields = fields
         .iter()
+        .filter(|(_, type_, _)| {
+            find_object(objects, type_).is_none() && !scalar::is_password(type_)
+        })
         .map(|(name, _, _)| format!("    {}", name.to_case(Case::Pascal)))
         .collect::<Vec<_>>()
         .join(",\n");
 
+    // Les relations graphe sont résolues par des champs calculés
+    let (object_attributes, graph_resolvers) = generate_graph_resolvers(&struct_name, relations);
+
     let content = ENTITY_TEMPLATE
         .replace("{imports}", &imports)
+        .replace("{patterns}", &generate_patterns(&struct_name, info))
+        .replace("{object_attributes}", &object_attributes)
+        .replace("{graph_resolvers}", &graph_resolvers)
         .replace("{name}", &struct_name)
         .replace("{fields}", &fields_def)
         .replace("{relations}", &relations_def)
         .replace("{field_inits}", &field_inits)
         .replace("{relation_inits}", &relation_inits)
+        .replace("{nested_types}", &nested_types)
         .replace("{input_fields}", &input_fields)
         .replace("{filter_fields}", &filter_fields)
         .replace("{relation_filters}", &relation_filters)
@@ -439,83 +847,380 @@ fn generate_entity_content(
     Ok(content)
 }
 
-/// Génère les imports pour les relations
-fn generate_relation_imports(relations: &[RelationType]) -> String {
+/// Génère les resolvers GraphQL des clés étrangères `belongsTo`, exposées sans le nom de la
+/// table comme l'identifiant de l'entité, et ceux qui parcourent les arêtes `manyToMany`
+fn generate_graph_resolvers(struct_name: &str, relations: &[RelationType]) -> (String, String) {
+    let resolvers = relations
+        .iter()
+        .filter_map(|relation| match relation {
+            RelationType::BelongsTo(target) => {
+                let foreign_key = format!("{}_id", module_name(target));
+                Some(format!(
+                    r#"    /// Identifiant de {target} (`{table}:abc` -> `abc`)
+    async fn {foreign_key}(&self) -> Option<ID> {{
+        self.{foreign_key}.as_ref().map(|id| ID(id.id.to_raw()))
+    }}"#,
+                    table = table_name(target)
+                ))
+            }
+            RelationType::ManyToMany(target, edge) => {
+                let table = table_name(target);
+                Some(format!(
+                    r#"    /// {target} reliés par ->{edge}->{table}
+    async fn {table}(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<{target}>> {{
+        let db = ctx.data::<Surreal<Client>>()?;
+        Ok({struct_name}Relations::{table}(db, &self.base.key()).await?)
+    }}"#
+                ))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if resolvers.is_empty() {
+        return (String::new(), String::new());
+    }
+
+    (
+        "#[graphql(complex)]\n".to_string(),
+        format!(
+            "\n\n#[ComplexObject]\nimpl {} {{\n{}\n}}",
+            struct_name,
+            resolvers.join("\n\n")
+        ),
+    )
+}
+
+/// Génère les imports des relations, des scalaires et des types externes utilisés par l'entité
+fn generate_imports(struct_name: &str, info: &EntityInfo) -> String {
+    let relations = &info.relations;
     let mut imports = Vec::new();
     for relation in relations {
         match relation {
             RelationType::HasOne(target)
             | RelationType::HasMany(target)
-            | RelationType::BelongsTo(target) => {
+            | RelationType::BelongsTo(target)
+            | RelationType::ManyToMany(target, _) => {
                 imports.push(format!(
                     "use crate::entities::{}::{};",
-                    target.to_lowercase(),
+                    module_name(target),
                     target
                 ));
             }
         }
     }
+
+    // `ID` des clés étrangères et des filtres `<cible>_ids`
+    if relations.iter().any(|relation| {
+        matches!(
+            relation,
+            RelationType::BelongsTo(_) | RelationType::ManyToMany(..)
+        )
+    }) {
+        imports.push("use async_graphql::ID;".to_string());
+    }
+
+    let scalars = used_scalars(info);
+    if !scalars.is_empty() {
+        imports.push(format!(
+            "use crate::graphql::scalars::{{{}}};",
+            scalars.join(", ")
+        ));
+    }
+    if field_types(info).any(|type_| type_.contains("Json")) {
+        imports.push("use serde_json::Value as Json;".to_string());
+    }
+
+    if relations
+        .iter()
+        .any(|relation| matches!(relation, RelationType::BelongsTo(_)))
+    {
+        imports.push("use surrealdb::sql::Thing;".to_string());
+    }
+
+    if relations.iter().any(|relation| {
+        matches!(
+            relation,
+            RelationType::BelongsTo(_) | RelationType::ManyToMany(..)
+        )
+    }) {
+        imports.push("use async_graphql::ComplexObject;".to_string());
+    }
+
+    if relations
+        .iter()
+        .any(|relation| matches!(relation, RelationType::ManyToMany(..)))
+    {
+        imports.push("use async_graphql::Context;".to_string());
+        imports.push("use surrealdb::engine::remote::ws::Client;".to_string());
+        imports.push("use surrealdb::Surreal;".to_string());
+        imports.push(format!(
+            "use crate::repositories::{}_relations::{}Relations;",
+            module_name(struct_name),
+            struct_name
+        ));
+    }
     imports.join("\n")
 }
 
-/// Génère un champ avec ses validations
-fn generate_field_with_validation(name: &str, type_: &str, validation: &FieldValidation) -> String {
-    let mut validations = Vec::new();
+/// Types des champs de l'entité et de ses objets imbriqués
+fn field_types(info: &EntityInfo) -> impl Iterator<Item = &str> {
+    info.fields
+        .iter()
+        .chain(info.objects.iter().flat_map(|object| object.fields.iter()))
+        .map(|(_, type_, _)| type_.as_str())
+}
+
+/// Scalaires de `graphql/scalars` utilisés par l'entité, dans l'ordre de `SCALARS`
+pub fn used_scalars(info: &EntityInfo) -> Vec<&'static str> {
+    let used = field_types(info)
+        .flat_map(scalar::scalars_in)
+        .collect::<Vec<_>>();
+    scalar::SCALARS
+        .into_iter()
+        .filter(|scalar| used.contains(scalar))
+        .collect()
+}
+
+/// Type Rust d'un champ ; `DateTime` seul désigne `DateTime<Utc>`
+fn rust_type(type_: &str) -> String {
+    type_
+        .replace("DateTime<Utc>", "DateTime")
+        .replace("DateTime", "DateTime<Utc>")
+}
 
-    if validation.required {
-        validations.push("#[validate(required)]".to_string());
+/// Nom de la regex statique d'un champ `pattern=...` (`ARTICLE_SLUG_PATTERN`)
+fn pattern_static(owner: &str, field: &str) -> String {
+    format!("{}_{}_PATTERN", owner, field).to_case(Case::UpperSnake)
+}
+
+/// Génère les regex statiques référencées par `#[validate(regex(path = ...))]`
+fn generate_patterns(struct_name: &str, info: &EntityInfo) -> String {
+    let owners = std::iter::once((struct_name, &info.fields)).chain(
+        info.objects
+            .iter()
+            .map(|object| (object.name.as_str(), &object.fields)),
+    );
+
+    let mut patterns = String::new();
+    for (owner, fields) in owners {
+        for (name, type_, validation) in fields {
+            let Some(pattern) = &validation.pattern else {
+                continue;
+            };
+            if !is_string(type_) {
+                continue;
+            }
+            patterns.push_str(&format!(
+                "\nstatic {}: once_cell::sync::Lazy<regex::Regex> =\n    once_cell::sync::Lazy::new(|| regex::Regex::new({:?}).unwrap());\n",
+                pattern_static(owner, name),
+                pattern,
+            ));
+        }
     }
-    if validation.unique {
-        validations.push("#[validate(custom = \"validate_unique\")]".to_string());
+    patterns
+}
+
+/// Type chaîne, éventuellement optionnel
+fn is_string(type_: &str) -> bool {
+    generic_argument(type_, "Option")
+        .as_deref()
+        .unwrap_or(type_)
+        == "String"
+}
+
+/// Règles `#[validate(...)]` d'un champ, limitées à celles que validator accepte pour son type ;
+/// les autres sont renvoyées à part pour être conservées dans un commentaire `/// Validate:`
+fn validation_rules(
+    owner: &str,
+    (name, type_, validation): &Field,
+    info: &EntityInfo,
+) -> (Vec<String>, Vec<String>) {
+    let optional = generic_argument(type_, "Option");
+    let inner = optional.as_deref().unwrap_or(type_);
+    let numeric = matches!(
+        inner,
+        "i32" | "i64" | "u32" | "u64" | "usize" | "f32" | "f64"
+    );
+    let (mut rules, mut skipped) = (Vec::new(), Vec::new());
+
+    if find_object(&info.objects, type_).is_some() {
+        // Valide aussi les champs de l'objet imbriqué
+        let rule = if validation.required && optional.is_some() {
+            "required_nested"
+        } else {
+            ""
+        };
+        rules.push(rule.to_string());
+    } else if validation.required && optional.is_some() {
+        rules.push("required".to_string());
     }
+
+    let mut rule = |supported: bool, rule: String| {
+        if supported {
+            rules.push(rule)
+        } else {
+            skipped.push(rule)
+        }
+    };
     if validation.email {
-        validations.push("#[validate(email)]".to_string());
+        rule(inner == "String", "email".to_string());
     }
     if validation.url {
-        validations.push("#[validate(url)]".to_string());
+        rule(inner == "String", "url".to_string());
     }
-    if let Some(min) = &validation.min {
-        let validation = format!("#[validate(range(min = \"{}\"))]", min);
-        validations.push(validation);
+    let bounds = |min: Option<String>, max: Option<String>| {
+        min.map(|min| format!("min = {}", min))
+            .into_iter()
+            .chain(max.map(|max| format!("max = {}", max)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    if validation.min_length.is_some() || validation.max_length.is_some() {
+        rule(
+            inner == "String" || inner.starts_with("Vec<"),
+            format!(
+                "length({})",
+                bounds(
+                    validation.min_length.map(|v| v.to_string()),
+                    validation.max_length.map(|v| v.to_string())
+                )
+            ),
+        );
     }
-    if let Some(max) = &validation.max {
-        let validation = format!("#[validate(range(max = \"{}\"))]", max);
-        validations.push(validation);
+    if validation.min.is_some() || validation.max.is_some() {
+        rule(
+            numeric,
+            format!(
+                "range({})",
+                bounds(validation.min.clone(), validation.max.clone())
+            ),
+        );
     }
-    if let Some(min_length) = validation.min_length {
-        let validation = format!("#[validate(length(min = {}))]", min_length);
-        validations.push(validation);
+    if validation.pattern.is_some() {
+        rule(
+            inner == "String",
+            format!("regex(path = \"{}\")", pattern_static(owner, name)),
+        );
     }
-    if let Some(max_length) = validation.max_length {
-        let validation = format!("#[validate(length(max = {}))]", max_length);
-        validations.push(validation);
+
+    (rules, skipped)
+}
+
+/// Attribut `#[validate(...)]` d'un champ, vide s'il n'a aucune règle applicable
+fn validate_attribute(rules: &[String]) -> Option<String> {
+    match rules {
+        [] => None,
+        [nested] if nested.is_empty() => Some("#[validate]".to_string()),
+        _ => Some(format!(
+            "#[validate({})]",
+            rules
+                .iter()
+                .filter(|rule| !rule.is_empty())
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Génère un champ avec ses validations ; les règles que validator ne peut vérifier
+/// (`required` d'un champ non optionnel, `unique`...) sont conservées en commentaires
+///
+/// Un `Password` de l'entité n'est pas sérialisé : l'entité n'est que lue depuis la base, et
+/// seul l'Input (ou un objet imbriqué, qui sert aussi d'Input) écrit le hash.
+fn generate_field_with_validation(
+    owner: &str,
+    field: &Field,
+    info: &EntityInfo,
+    entity: bool,
+) -> String {
+    let (name, type_, validation) = field;
+    let (rules, skipped) = validation_rules(owner, field, info);
+    let mut lines = Vec::new();
+
+    if let Some(default) = &validation.default {
+        lines.push(format!("/// Default: {}", default));
+    }
+    if validation.required && generic_argument(type_, "Option").is_none() {
+        lines.push("/// Required".to_string());
+    }
+    if validation.unique {
+        lines.push("/// Unique".to_string());
     }
     if let Some(pattern) = &validation.pattern {
-        let validation = format!("#[validate(regex(path = \"{}\"))]", pattern);
-        validations.push(validation);
+        lines.push(format!("/// Pattern: {}", pattern));
     }
+    if !skipped.is_empty() {
+        lines.push(format!("/// Validate: {}", skipped.join(", ")));
+    }
+    lines.extend(validate_attribute(&rules));
+    if scalar::is_password(type_) {
+        lines.push("#[graphql(skip_output)]".to_string());
+        if entity {
+            lines.push("#[serde(skip_serializing)]".to_string());
+        }
+    }
+    lines.push(format!("pub {}: {}", name, rust_type(type_)));
+
+    format!("    {}", lines.join("\n    "))
+}
 
-    let validations_str = validations.join("\n    ");
-    format!("    {}\n    pub {}: {}", validations_str, name, type_)
+/// Expression Rust de la valeur initiale d'un champ dans `new()`
+fn default_expr(type_: &str, default: Option<&str>, info: &EntityInfo) -> String {
+    let Some(value) = default else {
+        return "Default::default()".to_string();
+    };
+    if let Some(inner) = generic_argument(type_, "Option") {
+        return format!("Some({})", default_expr(&inner, Some(value), info));
+    }
+    if let Some(enum_type) = find_enum(&info.enums, type_) {
+        return format!("{}::{}", enum_type.name, value.to_case(Case::Pascal));
+    }
+
+    match type_ {
+        "i32" | "i64" | "u32" | "u64" | "usize" | "bool" => value.to_string(),
+        "f32" | "f64" => format!("{:?}", value.parse::<f64>().unwrap_or_default()),
+        "String" => format!("{:?}.to_string()", value),
+        // Les scalaires valident leur valeur par `FromStr`
+        _ if scalar::SCALARS.contains(&type_) => format!("{:?}.parse().unwrap_or_default()", value),
+        _ => "Default::default()".to_string(),
+    }
 }
 
-/// Génère le champ pour une relation
+/// Génère le champ pour une relation ; les enregistrements liés ne sont pas stockés avec
+/// l'entité et prennent leur valeur par défaut à la lecture
 fn generate_relation_field(relation: &RelationType) -> String {
     match relation {
         RelationType::HasOne(target) => {
-            format!("    pub {}: Option<{}>", target.to_lowercase(), target)
+            format!(
+                "    #[serde(default)]\n    pub {}: Option<{}>",
+                module_name(target),
+                target
+            )
         }
         RelationType::HasMany(target) => {
-            format!("    pub {}: Vec<{}>", target.to_lowercase(), target)
+            format!(
+                "    #[serde(default)]\n    pub {}: Vec<{}>",
+                module_name(target),
+                target
+            )
         }
         RelationType::BelongsTo(target) => {
             format!(
-                "    pub {}_id: ID,\n    pub {}: Option<{}>",
-                target.to_lowercase(),
-                target.to_lowercase(),
+                "    #[graphql(skip)]\n    pub {}_id: Option<Thing>,\n    #[serde(default)]\n    pub {}: Option<{}>",
+                module_name(target),
+                module_name(target),
                 target
             )
         }
+        RelationType::ManyToMany(target, edge) => {
+            let table = table_name(target);
+            format!(
+                "    /// Graph edge ->{}->{}\n    #[graphql(skip)]\n    #[serde(default)]\n    pub {}: Vec<{}>",
+                edge, table, table, target
+            )
+        }
     }
 }
 
@@ -523,43 +1228,111 @@ fn generate_relation_field(relation: &RelationType) -> String {
 fn generate_relation_init(relation: &RelationType) -> String {
     match relation {
         RelationType::HasOne(target) => {
-            format!("            {}: None", target.to_lowercase())
+            format!("            {}: None", module_name(target))
         }
         RelationType::HasMany(target) => {
-            format!("            {}: Vec::new()", target.to_lowercase())
+            format!("            {}: Vec::new()", module_name(target))
         }
         RelationType::BelongsTo(target) => {
             format!(
-                "            {}_id: ID::default(),\n            {}: None",
-                target.to_lowercase(),
-                target.to_lowercase()
+                "            {}_id: None,\n            {}: None",
+                module_name(target),
+                module_name(target)
             )
         }
+        RelationType::ManyToMany(target, _) => {
+            format!("            {}: Vec::new()", table_name(target))
+        }
     }
 }
 
-/// Génère un champ d'input avec ses validations
-fn generate_input_field(name: &str, type_: &str, validation: &FieldValidation) -> String {
-    let mut validations = Vec::new();
-    if validation.required {
-        validations.push("    #[validate(required)]");
-    }
-    let validations_str = validations.join("\n");
-    format!("    {}\n    pub {}: {}", validations_str, name, type_)
+/// Génère un champ d'input avec les validations que validator peut vérifier
+fn generate_input_field(owner: &str, field: &Field, info: &EntityInfo) -> String {
+    let (name, type_, _) = field;
+    let (rules, _) = validation_rules(owner, field, info);
+    let attribute = validate_attribute(&rules)
+        .map(|attribute| format!("    {}\n", attribute))
+        .unwrap_or_default();
+    format!("{}    pub {}: {}", attribute, name, rust_type(type_))
+}
+
+/// Génère une structure d'objet imbriqué, utilisable en sortie comme en entrée, et son filtre
+fn generate_object(object: &ObjectType, info: &EntityInfo) -> String {
+    let fields = object
+        .fields
+        .iter()
+        .map(|field| generate_field_with_validation(&object.name, field, info, false))
+        .collect::<Vec<_>>()
+        .join(",\n\n");
+
+    format!(
+        r#"
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject, InputObject, Validate)]
+#[graphql(input_name = "{name}Input")]
+pub struct {name} {{
+{fields}
+}}
+
+#[derive(InputObject, Default)]
+pub struct {name}Filter {{
+{filter_fields}
+}}
+"#,
+        name = object.name,
+        fields = fields,
+        filter_fields = generate_filter_fields(&object.fields, info)
+    )
+}
+
+/// Génère un enum GraphQL, stocké en base sous la forme de ses valeurs en snake_case
+fn generate_enum(enum_type: &EnumType) -> String {
+    let variants = enum_type
+        .variants
+        .iter()
+        .zip(enum_type.values())
+        .enumerate()
+        .map(|(i, (variant, value))| {
+            let default = if i == 0 { "    #[default]\n" } else { "" };
+            format!("{default}    #[serde(rename = \"{value}\")]\n    {variant},")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"
+#[derive(Enum, Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum {} {{
+{}
+}}
+"#,
+        enum_type.name, variants
+    )
 }
 
 /// Génère les champs de filtre
-fn generate_filter_fields(fields: &[(String, String, FieldValidation)]) -> String {
+fn generate_filter_fields(fields: &[Field], info: &EntityInfo) -> String {
     fields
         .iter()
-        .map(|(name, type_, _)| match type_.as_str() {
-            "String" => format!("    pub {}_contains: Option<String>", name),
-            "i32" | "i64" => format!(
-                "    pub {}_min: Option<{}>,\n    pub {}_max: Option<{}>",
-                name, type_, name, type_
-            ),
-            "bool" => format!("    pub {}: Option<bool>", name),
-            _ => format!("    pub {}: Option<{}>", name, type_),
+        .filter(|(_, type_, _)| !scalar::is_password(type_))
+        .map(|(name, type_, _)| {
+            if let Some(object) = find_object(&info.objects, type_) {
+                return format!("    pub {}: Option<{}Filter>", name, object.name);
+            }
+            if let Some(enum_type) = find_enum(&info.enums, type_) {
+                return format!(
+                    "    pub {name}_in: Option<Vec<{0}>>,\n    pub {name}_not_in: Option<Vec<{0}>>",
+                    enum_type.name
+                );
+            }
+            match type_.as_str() {
+                "String" => format!("    pub {}_contains: Option<String>", name),
+                "i32" | "i64" => format!(
+                    "    pub {}_min: Option<{}>,\n    pub {}_max: Option<{}>",
+                    name, type_, name, type_
+                ),
+                "bool" => format!("    pub {}: Option<bool>", name),
+                _ => format!("    pub {}: Option<{}>", name, rust_type(type_)),
+            }
         })
         .collect::<Vec<_>>()
         .join(",\n")
@@ -571,52 +1344,37 @@ fn generate_relation_filters(relations: &[RelationType]) -> String {
         .iter()
         .map(|relation| match relation {
             RelationType::HasOne(target) | RelationType::BelongsTo(target) => {
-                format!("    pub has_{}: Option<bool>", target.to_lowercase())
+                format!("    pub has_{}: Option<bool>", module_name(target))
             }
             RelationType::HasMany(target) => {
                 format!(
-                    "    pub has_{}: Option<bool>,\n    pub {}_count_min: Option<i32>,\n    pub {}_count_max: Option<i32>",
-                    target.to_lowercase(),
-                    target.to_lowercase(),
-                    target.to_lowercase()
+                    "    pub has_{0}: Option<bool>,\n    pub {0}_count_min: Option<i32>,\n    pub {0}_count_max: Option<i32>",
+                    module_name(target)
                 )
             }
+            RelationType::ManyToMany(target, _) => {
+                format!("    pub {}_ids: Option<Vec<ID>>", module_name(target))
+            }
         })
         .collect::<Vec<_>>()
         .join(",\n")
 }
 
-/// Met à jour le fichier mod.rs des entités
-fn update_entities_mod(name: &str, relations: &[RelationType]) -> Result<(), String> {
+/// Met à jour le fichier mod.rs des entités ; les cibles des relations y sont déclarées
+/// lorsqu'elles sont générées à leur tour
+fn update_entities_mod(name: &str) -> Result<(), String> {
     let mod_path = "backend/src/entities/mod.rs";
     let mut content = fs::read_to_string(mod_path).unwrap_or_else(|_| String::new());
 
-    let module_name = name.to_lowercase();
+    let entity_module = module_name(name);
     let pascal_name = name.to_case(Case::Pascal);
 
-    if !content.contains(&format!("pub mod {};", module_name)) {
-        if !content.is_empty() {
+    if !content.contains(&format!("pub mod {};", entity_module)) {
+        if !content.is_empty() && !content.ends_with('\n') {
             content.push('\n');
         }
-        content.push_str(&format!("pub mod {};\n", module_name));
-        content.push_str(&format!("pub use {}::{};\n", module_name, pascal_name));
-
-        for relation in relations {
-            match relation {
-                RelationType::HasOne(target)
-                | RelationType::HasMany(target)
-                | RelationType::BelongsTo(target) => {
-                    if !content.contains(&format!("pub mod {};", target.to_lowercase())) {
-                        content.push_str(&format!("pub mod {};\n", target.to_lowercase()));
-                        content.push_str(&format!(
-                            "pub use {}::{};\n",
-                            target.to_lowercase(),
-                            target
-                        ));
-                    }
-                }
-            }
-        }
+        content.push_str(&format!("pub mod {};\n", entity_module));
+        content.push_str(&format!("pub use {}::{};\n", entity_module, pascal_name));
     }
 
     fs::write(mod_path, content).map_err(|e| format!("Failed to update entities/mod.rs: {}", e))?;
@@ -624,22 +1382,202 @@ fn update_entities_mod(name: &str, relations: &[RelationType]) -> Result<(), Str
     Ok(())
 }
 
-/// Met à jour le fichier mod.rs des types GraphQL
-fn update_graphql_mod(name: &str) -> Result<(), String> {
-    let mod_path = "backend/src/graphql/types/mod.rs";
-    let mut content = fs::read_to_string(mod_path).unwrap_or_else(|_| String::new());
+/// Écrit `entities/base_entity.rs` s'il n'existe pas et le déclare dans `entities/mod.rs`
+pub fn ensure_base_entity(backend_src: &str) -> Result<(), String> {
+    let entities_dir = format!("{}/entities", backend_src);
+    fs::create_dir_all(&entities_dir)
+        .map_err(|e| format!("Failed to create entities directory: {}", e))?;
 
-    let module_name = name.to_lowercase();
-    if !content.contains(&format!("pub mod {};", module_name)) {
-        if !content.is_empty() {
+    let base_path = format!("{}/base_entity.rs", entities_dir);
+    if !Path::new(&base_path).exists() {
+        fs::write(&base_path, BASE_ENTITY_TEMPLATE)
+            .map_err(|e| format!("Failed to write {}: {}", base_path, e))?;
+    }
+
+    let mod_path = format!("{}/mod.rs", entities_dir);
+    let mut content = fs::read_to_string(&mod_path).unwrap_or_default();
+    if !content.contains("pub mod base_entity;") {
+        if !content.is_empty() && !content.ends_with('\n') {
             content.push('\n');
         }
-        content.push_str(&format!("pub mod {};\n", module_name));
-        content.push_str(&format!("pub use {}::*;\n", module_name));
+        content.push_str("pub mod base_entity;\n");
+        fs::write(&mod_path, content)
+            .map_err(|e| format!("Failed to update entities/mod.rs: {}", e))?;
     }
+    Ok(())
+}
 
-    fs::write(mod_path, content)
-        .map_err(|e| format!("Failed to update graphql/types/mod.rs: {}", e))?;
+/// Génère `repositories/<entity>_relations.rs` : création, suppression et parcours des arêtes `manyToMany`
+fn generate_relations_repository(
+    name: &str,
+    relations: &[RelationType],
+) -> Result<Option<String>, String> {
+    let struct_name = name.to_case(Case::Pascal);
+    let relations_module = format!("{}_relations", module_name(name));
+    let table = table_name(name);
+    let from_id = format!("{}_id", name.to_case(Case::Snake));
 
-    Ok(())
+    let helpers = relations
+        .iter()
+        .filter_map(|relation| match relation {
+            RelationType::ManyToMany(target, edge) => Some((target, edge)),
+            _ => None,
+        })
+        .map(|(target, edge)| {
+            let target_table = table_name(target);
+            let target_name = target.to_case(Case::Snake);
+            format!(
+                r#"    /// RELATE {table}:$from->{edge}->{target_table}:$to
+    pub async fn relate_{target_name}(
+        db: &Surreal<Client>,
+        {from_id}: &str,
+        {target_name}_id: &str,
+    ) -> Result<(), surrealdb::Error> {{
+        db.query("RELATE (type::thing('{table}', $from))->{edge}->(type::thing('{target_table}', $to))")
+            .bind(("from", {from_id}.to_string()))
+            .bind(("to", {target_name}_id.to_string()))
+            .await?
+            .check()?;
+        Ok(())
+    }}
+
+    /// Supprime l'arête {table}:$from->{edge}->{target_table}:$to
+    pub async fn unrelate_{target_name}(
+        db: &Surreal<Client>,
+        {from_id}: &str,
+        {target_name}_id: &str,
+    ) -> Result<(), surrealdb::Error> {{
+        db.query("DELETE {edge} WHERE in = type::thing('{table}', $from) AND out = type::thing('{target_table}', $to)")
+            .bind(("from", {from_id}.to_string()))
+            .bind(("to", {target_name}_id.to_string()))
+            .await?
+            .check()?;
+        Ok(())
+    }}
+
+    /// {target} reliés par ->{edge}->{target_table}
+    pub async fn {target_table}(
+        db: &Surreal<Client>,
+        {from_id}: &str,
+    ) -> Result<Vec<{target}>, surrealdb::Error> {{
+        db.query("SELECT * FROM type::thing('{table}', $from)->{edge}->{target_table}")
+            .bind(("from", {from_id}.to_string()))
+            .await?
+            .take(0)
+    }}"#
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if helpers.is_empty() {
+        return Ok(None);
+    }
+
+    let imports = relations
+        .iter()
+        .filter_map(|relation| match relation {
+            RelationType::ManyToMany(target, _) => Some(format!(
+                "use crate::entities::{}::{};",
+                module_name(target),
+                target
+            )),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let content = format!(
+        "use surrealdb::engine::remote::ws::Client;\nuse surrealdb::Surreal;\n{}\n\n/// Relations graphe de {}\npub struct {}Relations;\n\nimpl {}Relations {{\n{}\n}}\n",
+        imports,
+        struct_name,
+        struct_name,
+        struct_name,
+        helpers.join("\n\n")
+    );
+
+    fs::create_dir_all("backend/src/repositories")
+        .map_err(|e| format!("Failed to create repositories directory: {}", e))?;
+    let repository_path = format!("backend/src/repositories/{}.rs", relations_module);
+    fs::write(&repository_path, content)
+        .map_err(|e| format!("Failed to write relations repository: {}", e))?;
+
+    let mod_path = "backend/src/repositories/mod.rs";
+    let mut mod_content = fs::read_to_string(mod_path).unwrap_or_default();
+    if !mod_content.contains(&format!("pub mod {};", relations_module)) {
+        mod_content.push_str(&format!("pub mod {};\n", relations_module));
+        fs::write(mod_path, mod_content)
+            .map_err(|e| format!("Failed to update repositories/mod.rs: {}", e))?;
+    }
+
+    Ok(Some(repository_path))
+}
+
+/// Génère `frontend/app/types/<entity>.ts` lorsque le projet a un frontend Remix,
+/// les enums devenant des unions des valeurs exposées par GraphQL
+fn generate_frontend_types(info: &EntityInfo) -> Result<Option<String>, String> {
+    if !Path::new("frontend/app").exists() {
+        return Ok(None);
+    }
+
+    let mut sections = vec!["// Generated by Nebulis CLI".to_string()];
+    for enum_type in &info.enums {
+        let values = enum_type
+            .variants
+            .iter()
+            .map(|variant| format!("'{}'", variant.to_case(Case::UpperSnake)))
+            .collect::<Vec<_>>();
+        sections.push(format!(
+            "export type {} = {};",
+            enum_type.name,
+            values.join(" | ")
+        ));
+    }
+
+    let interface = |name: &str, fields: &[Field]| {
+        let fields = fields
+            .iter()
+            .filter(|(_, type_, _)| !scalar::is_password(type_))
+            .map(|(field_name, type_, _)| {
+                format!(
+                    "  {}: {};",
+                    field_name.to_case(Case::Camel),
+                    typescript_type(type_, info)
+                )
+            })
+            .collect::<Vec<_>>();
+        format!("export interface {} {{\n{}\n}}", name, fields.join("\n"))
+    };
+    for object in &info.objects {
+        sections.push(interface(&object.name, &object.fields));
+    }
+    sections.push(interface(&info.name.to_case(Case::Pascal), &info.fields));
+
+    fs::create_dir_all("frontend/app/types")
+        .map_err(|e| format!("Failed to create frontend types directory: {}", e))?;
+    let types_path = format!("frontend/app/types/{}.ts", module_name(&info.name));
+    fs::write(&types_path, sections.join("\n\n") + "\n")
+        .map_err(|e| format!("Failed to write frontend types: {}", e))?;
+
+    Ok(Some(types_path))
+}
+
+/// Type TypeScript d'un champ tel que renvoyé par l'API GraphQL
+fn typescript_type(type_: &str, info: &EntityInfo) -> String {
+    if let Some(inner) = generic_argument(type_, "Option") {
+        return format!("{} | null", typescript_type(&inner, info));
+    }
+    if let Some(inner) = generic_argument(type_, "Vec") {
+        return format!("{}[]", typescript_type(&inner, info));
+    }
+    if find_object(&info.objects, type_).is_some() || find_enum(&info.enums, type_).is_some() {
+        return type_.to_string();
+    }
+
+    match type_ {
+        "i32" | "i64" | "u32" | "u64" | "usize" | "f32" | "f64" => "number",
+        "bool" => "boolean",
+        "Json" => "unknown",
+        _ => "string",
+    }
+    .to_string()
 }
@@ -0,0 +1,183 @@
+// src/generators/entity_builder.rs
+use crate::generators::entity::{self, entity_path, EntityInfo, VALID_TYPES};
+use crate::generators::fields;
+use colored::*;
+use std::io::{BufRead, Write};
+
+/// Types proposés : ceux de `validate_field_type`, hors pseudo-types relationnels
+/// (les relations font l'objet de leur propre question)
+fn field_types() -> Vec<&'static str> {
+    VALID_TYPES
+        .iter()
+        .copied()
+        .filter(|type_| !matches!(*type_, "HasOne" | "HasMany" | "BelongsTo"))
+        .collect()
+}
+
+/// Construit une entité question par question, chaque réponse étant validée par le parser de
+/// `--fields` ; renvoie `None` si la génération n'est pas confirmée
+pub fn build<R: BufRead, W: Write>(
+    name: &str,
+    input: &mut R,
+    output: &mut W,
+) -> Result<Option<EntityInfo>, String> {
+    let mut prompt = Prompt { input, output };
+    let mut definitions: Vec<String> = Vec::new();
+
+    prompt.say(&format!(
+        "{} {} (leave the field name empty to finish)",
+        "Building entity".blue(),
+        name
+    ))?;
+    let types = field_types();
+    let listing = types
+        .iter()
+        .enumerate()
+        .map(|(i, type_)| format!("{}) {}", i + 1, type_))
+        .collect::<Vec<_>>();
+    prompt.say(&format!(
+        "Types: {}, or enum(A,B), or Object{{field:Type,...}}",
+        listing.join("  ")
+    ))?;
+
+    loop {
+        let field_name = prompt.ask_valid("Field name", &definitions, name, |answer| {
+            format!("{}:String", answer)
+        })?;
+        if field_name.is_empty() {
+            break;
+        }
+
+        let type_ = loop {
+            let type_ = prompt.ask_valid("Type", &definitions, name, |answer| {
+                format!("{}:{}", field_name, resolve_type(answer, &types))
+            })?;
+            if !type_.is_empty() {
+                break type_;
+            }
+            prompt.say(&format!("{} A type is required", "✗".red()))?;
+        };
+        let mut definition = format!("{}:{}", field_name, resolve_type(&type_, &types));
+
+        if prompt.confirm("Optional?", false)? {
+            definition = definition.replacen(':', "?:", 1);
+        }
+
+        let default = prompt.ask_valid(
+            "Default value (empty for none)",
+            &definitions,
+            name,
+            |answer| format!("{}={}", definition, answer),
+        )?;
+        if !default.is_empty() {
+            definition = format!("{}={}", definition, default);
+        }
+
+        let rules = prompt.ask_valid(
+            "Validations, e.g. required|min_length=3 (empty for none)",
+            &definitions,
+            name,
+            |answer| format!("{}|{}", definition, answer),
+        )?;
+        if !rules.is_empty() {
+            definition = format!("{}|{}", definition, rules);
+        }
+
+        definitions.push(definition);
+    }
+
+    loop {
+        let relation = prompt.ask_valid(
+            "Relation, e.g. belongsTo->User or manyToMany->Tag via tagged (empty to finish)",
+            &definitions,
+            name,
+            |answer| answer.to_string(),
+        )?;
+        if relation.is_empty() {
+            break;
+        }
+        definitions.push(relation);
+    }
+
+    let info = fields::parse(name, &definitions)?;
+    prompt.say(&format!("\n{}\n", entity::preview(&info)?))?;
+    if !prompt.confirm(&format!("Write {}?", entity_path(name)), true)? {
+        return Ok(None);
+    }
+    Ok(Some(info))
+}
+
+/// Un numéro de la liste des types désigne le type correspondant
+fn resolve_type(answer: &str, types: &[&str]) -> String {
+    answer
+        .parse::<usize>()
+        .ok()
+        .and_then(|i| types.get(i.wrapping_sub(1)))
+        .map_or_else(|| answer.to_string(), |type_| type_.to_string())
+}
+
+struct Prompt<'a, R, W> {
+    input: &'a mut R,
+    output: &'a mut W,
+}
+
+impl<R: BufRead, W: Write> Prompt<'_, R, W> {
+    fn say(&mut self, message: &str) -> Result<(), String> {
+        writeln!(self.output, "{}", message).map_err(|e| format!("Failed to write prompt: {}", e))
+    }
+
+    fn ask(&mut self, question: &str) -> Result<String, String> {
+        write!(self.output, "{} ", format!("{}:", question).cyan())
+            .and_then(|_| self.output.flush())
+            .map_err(|e| format!("Failed to write prompt: {}", e))?;
+
+        let mut answer = String::new();
+        let read = self
+            .input
+            .read_line(&mut answer)
+            .map_err(|e| format!("Failed to read answer: {}", e))?;
+        if read == 0 {
+            return Err("Input closed before the entity was complete (use --fields or --no-interactive in scripts)".into());
+        }
+        Ok(answer.trim().to_string())
+    }
+
+    /// Repose la question tant que la définition construite à partir de la réponse est invalide
+    fn ask_valid(
+        &mut self,
+        question: &str,
+        definitions: &[String],
+        name: &str,
+        definition: impl Fn(&str) -> String,
+    ) -> Result<String, String> {
+        loop {
+            let answer = self.ask(question)?;
+            if answer.is_empty() {
+                return Ok(answer);
+            }
+
+            let mut candidate = definitions.to_vec();
+            candidate.push(definition(&answer));
+            match fields::parse(name, &candidate) {
+                Ok(_) => return Ok(answer),
+                Err(e) => self.say(&format!("{} {}", "✗".red(), e))?,
+            }
+        }
+    }
+
+    fn confirm(&mut self, question: &str, default: bool) -> Result<bool, String> {
+        let hint = if default { "[Y/n]" } else { "[y/N]" };
+        loop {
+            match self
+                .ask(&format!("{} {}", question, hint))?
+                .to_lowercase()
+                .as_str()
+            {
+                "" => return Ok(default),
+                "y" | "yes" => return Ok(true),
+                "n" | "no" => return Ok(false),
+                _ => self.say("Please answer y or n")?,
+            }
+        }
+    }
+}
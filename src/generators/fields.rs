@@ -0,0 +1,460 @@
+// src/generators/fields.rs
+// Parser des définitions `--fields`
+//
+// Grammaire (les espaces séparent les définitions, sauf entre guillemets et dans `{}`/`()`) :
+//
+// ```text
+// definitions := definition ((',' | ' ') definition)*
+// definition  := relation | field
+// relation    := ('hasOne' | 'hasMany' | 'belongsTo') '->' IDENT
+//              | 'manyToMany' '->' IDENT 'via' IDENT
+// field       := IDENT '?'? ':' type ('=' value)? ('|' rule)* (' ' rule)*
+// type        := 'enum(' IDENT (',' IDENT)* ')'
+//              | IDENT '{' definitions '}'
+//              | IDENT ('<' type (',' type)* '>')?
+// rule        := IDENT ('=' value)?
+// value       := '"' ... '"' | "'" ... "'" | texte jusqu'au prochain espace, ',' ou '|'
+//                (entre guillemets, seuls \" \' et \\ sont des échappements)
+// ```
+use crate::generators::entity::{
+    find_enum, find_object, parse_enum, parse_validations, validate_field_type, EntityInfo, Field,
+    FieldValidation, ObjectType, RelationType,
+};
+use convert_case::{Case, Casing};
+
+/// Parse les arguments de `--fields` de l'entité `name`
+///
+/// Les arguments sont rejoints par des espaces : le découpage du shell n'a donc pas
+/// d'importance, et les colonnes des erreurs se rapportent à cette ligne.
+pub fn parse(name: &str, fields: &[String]) -> Result<EntityInfo, String> {
+    let source = fields.join(" ");
+    let mut info = EntityInfo {
+        name: name.to_case(Case::Pascal),
+        ..Default::default()
+    };
+
+    Parser::new(&source)
+        .definitions(&mut info, None)
+        .map_err(|error| error.render(&source))?;
+    Ok(info)
+}
+
+/// Erreur de parsing, située par sa colonne dans la ligne de définitions
+#[derive(Debug)]
+struct ParseError {
+    column: usize,
+    message: String,
+}
+
+impl ParseError {
+    /// Message suivi de la ligne fautive et d'un `^` sous la colonne en cause
+    fn render(&self, source: &str) -> String {
+        format!(
+            "{} (column {})\n  {}\n  {}^",
+            self.message,
+            self.column + 1,
+            source,
+            " ".repeat(self.column)
+        )
+    }
+}
+
+type ParseResult<T> = Result<T, ParseError>;
+
+const RELATION_KINDS: [&str; 4] = ["hasOne", "hasMany", "belongsTo", "manyToMany"];
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(source: &str) -> Self {
+        Self {
+            chars: source.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    fn error<T>(&self, column: usize, message: impl Into<String>) -> ParseResult<T> {
+        Err(ParseError {
+            column,
+            message: message.into(),
+        })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char, context: &str) -> ParseResult<()> {
+        if self.eat(c) {
+            return Ok(());
+        }
+        let found = match self.peek() {
+            Some(found) => format!("found '{}'", found),
+            None => "found end of input".to_string(),
+        };
+        self.error(self.pos, format!("Expected '{}' {}, {}", c, context, found))
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace() || c == ',') {
+            self.pos += 1;
+        }
+    }
+
+    fn ident(&mut self, what: &str) -> ParseResult<String> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            let found = match self.peek() {
+                Some(found) => format!("found '{}'", found),
+                None => "found end of input".to_string(),
+            };
+            return self.error(start, format!("Expected {}, {}", what, found));
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    /// Position après l'identifiant commençant à `self.pos`
+    fn ident_end(&self) -> usize {
+        let mut end = self.pos;
+        while self
+            .chars
+            .get(end)
+            .is_some_and(|c| c.is_ascii_alphanumeric() || *c == '_')
+        {
+            end += 1;
+        }
+        end
+    }
+
+    /// Une valeur : chaîne entre guillemets, ou texte brut jusqu'au prochain séparateur
+    fn value(&mut self) -> ParseResult<String> {
+        let start = self.pos;
+        if let Some(quote @ ('"' | '\'')) = self.peek() {
+            self.pos += 1;
+            let mut value = String::new();
+            loop {
+                match self.peek() {
+                    None => return self.error(start, "Unterminated string"),
+                    Some('\\') if matches!(self.peek_at(1), Some('"' | '\'' | '\\')) => {
+                        value.push(self.chars[self.pos + 1]);
+                        self.pos += 2;
+                    }
+                    Some(c) if c == quote => {
+                        self.pos += 1;
+                        return Ok(value);
+                    }
+                    Some(c) => {
+                        value.push(c);
+                        self.pos += 1;
+                    }
+                }
+            }
+        }
+
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && !matches!(c, ',' | '|' | '}'))
+        {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return self.error(start, "Expected a value");
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    /// Définitions jusqu'à la fin de l'entrée, ou jusqu'au `}` d'un objet
+    fn definitions(
+        &mut self,
+        info: &mut EntityInfo,
+        mut object: Option<&mut ObjectType>,
+    ) -> ParseResult<()> {
+        loop {
+            self.skip_separators();
+            match self.peek() {
+                None => return Ok(()),
+                Some('}') if object.is_some() => return Ok(()),
+                _ => {}
+            }
+
+            let start = self.pos;
+            let name = self.ident("a field name")?;
+            if self.peek() == Some('-') && self.peek_at(1) == Some('>') {
+                if object.is_some() {
+                    return self.error(start, "Relations cannot be declared inside an object");
+                }
+                let relation = self.relation(start, &name)?;
+                info.relations.push(relation);
+            } else {
+                let field = self.field(name, info)?;
+                let siblings = match object.as_deref() {
+                    Some(object) => &object.fields,
+                    None => &info.fields,
+                };
+                if siblings.iter().any(|(other, _, _)| *other == field.0) {
+                    return self.error(start, format!("Field {} is declared twice", field.0));
+                }
+                match object.as_deref_mut() {
+                    Some(object) => object.fields.push(field),
+                    None => info.fields.push(field),
+                }
+            }
+        }
+    }
+
+    fn relation(&mut self, start: usize, kind: &str) -> ParseResult<RelationType> {
+        if !RELATION_KINDS.contains(&kind) {
+            return self.error(
+                start,
+                format!(
+                    "Invalid relation type: {}. Valid relation types are: {}",
+                    kind,
+                    RELATION_KINDS.join(", ")
+                ),
+            );
+        }
+        self.pos += 2;
+        let target = self.ident("a relation target")?;
+
+        Ok(match kind {
+            "hasOne" => RelationType::HasOne(target),
+            "hasMany" => RelationType::HasMany(target),
+            "belongsTo" => RelationType::BelongsTo(target),
+            _ => {
+                self.skip_spaces();
+                let via = self.pos;
+                if self.ident_end() == via
+                    || self.chars[via..self.ident_end()].iter().collect::<String>() != "via"
+                {
+                    return self.error(
+                        via,
+                        format!(
+                            "Missing edge table: expected manyToMany->{} via edge",
+                            target
+                        ),
+                    );
+                }
+                self.pos = self.ident_end();
+                self.skip_spaces();
+                let edge = self.ident("an edge table name")?;
+                RelationType::ManyToMany(target, edge.to_case(Case::Snake))
+            }
+        })
+    }
+
+    fn field(&mut self, name: String, info: &mut EntityInfo) -> ParseResult<Field> {
+        let optional_at = self.pos;
+        let optional = self.eat('?');
+        self.expect(':', &format!("after field name '{}'", name))?;
+
+        let type_start = self.pos;
+        let mut field_type = self.type_(&name, info)?;
+        check_type(&field_type, info).or_else(|e| self.error(type_start, e))?;
+        if optional {
+            check_optional(&name, &field_type).or_else(|e| self.error(optional_at, e))?;
+        }
+
+        let mut validation = FieldValidation::default();
+        if self.eat('=') {
+            let default_start = self.pos;
+            let default = self.value()?;
+            check_default(&field_type, &default, info).or_else(|e| self.error(default_start, e))?;
+            validation.default = Some(default);
+        }
+
+        if optional {
+            field_type = format!("Option<{}>", field_type);
+        }
+
+        // Règles `|required|min_length=3`, éventuellement suivies de règles séparées par des espaces
+        loop {
+            let rule_start = if self.eat('|') {
+                self.pos
+            } else {
+                let checkpoint = self.pos;
+                self.skip_spaces();
+                if self.starts_rule() {
+                    self.pos
+                } else {
+                    self.pos = checkpoint;
+                    break;
+                }
+            };
+
+            let rule_name = self.ident("a validation rule")?;
+            let rule = if self.eat('=') {
+                format!("{}={}", rule_name, self.value()?)
+            } else {
+                rule_name
+            };
+            parse_validations(&mut validation, &[&rule]).or_else(|e| self.error(rule_start, e))?;
+        }
+
+        Ok((name, field_type, validation))
+    }
+
+    /// Un identifiant qui n'ouvre pas une nouvelle définition (`name:`, `name?:`, `kind->`)
+    fn starts_rule(&self) -> bool {
+        let end = self.ident_end();
+        if end == self.pos {
+            return false;
+        }
+        match self.chars.get(end) {
+            Some(':' | '?') => false,
+            Some('-') => self.chars.get(end + 1) != Some(&'>'),
+            _ => true,
+        }
+    }
+
+    fn type_(&mut self, field_name: &str, info: &mut EntityInfo) -> ParseResult<String> {
+        let start = self.pos;
+        let name = self.ident("a type")?;
+
+        match self.peek() {
+            Some('(') if name == "enum" => {
+                self.pos += 1;
+                let mut variants = Vec::new();
+                loop {
+                    self.skip_separators();
+                    if self.eat(')') {
+                        break;
+                    }
+                    if self.peek().is_none() {
+                        return self.error(start, "Unclosed enum definition");
+                    }
+                    variants.push(self.ident("an enum variant")?);
+                }
+                parse_enum(field_name, &variants.join(","), info).or_else(|e| self.error(start, e))
+            }
+            Some('{') => {
+                self.pos += 1;
+                let object_name = name.to_case(Case::Pascal);
+                let mut object = ObjectType {
+                    name: object_name.clone(),
+                    fields: Vec::new(),
+                };
+                self.definitions(info, Some(&mut object))?;
+                if !self.eat('}') {
+                    return self.error(
+                        start,
+                        format!("Unclosed object definition for {}", object_name),
+                    );
+                }
+                if object.fields.is_empty() {
+                    return self.error(
+                        start,
+                        format!("Object {} must declare at least one field", object_name),
+                    );
+                }
+                if find_object(&info.objects, &object_name).is_some() {
+                    return self.error(start, format!("Object {} is declared twice", object_name));
+                }
+                info.objects.push(object);
+                Ok(object_name)
+            }
+            Some('<') => {
+                self.pos += 1;
+                let mut arguments = Vec::new();
+                loop {
+                    self.skip_spaces();
+                    arguments.push(self.type_(field_name, info)?);
+                    self.skip_spaces();
+                    if self.eat('>') {
+                        break;
+                    }
+                    self.expect(',', &format!("or '>' to close {}<...>", name))?;
+                }
+                Ok(format!("{}<{}>", name, arguments.join(", ")))
+            }
+            _ => Ok(name),
+        }
+    }
+}
+
+/// Vérifie un type, les objets et enums déclarés étant admis y compris dans `Option<>`/`Vec<>`
+pub fn check_type(type_: &str, info: &EntityInfo) -> Result<(), String> {
+    if find_object(&info.objects, type_).is_some() || find_enum(&info.enums, type_).is_some() {
+        return Ok(());
+    }
+    for wrapper in ["Option<", "Vec<"] {
+        if let Some(inner) = type_
+            .strip_prefix(wrapper)
+            .and_then(|inner| inner.strip_suffix('>'))
+        {
+            if validate_field_type(type_).is_ok() {
+                return Ok(());
+            }
+            return check_type(inner, info);
+        }
+    }
+    validate_field_type(type_)
+}
+
+/// Refuse `?` sur un type déjà optionnel, qui donnerait `Option<Option<T>>`
+pub fn check_optional(name: &str, type_: &str) -> Result<(), String> {
+    if type_.starts_with("Option<") {
+        return Err(format!(
+            "Field {} is marked optional twice: use {}?:T or {}:Option<T>",
+            name, name, name
+        ));
+    }
+    Ok(())
+}
+
+/// Vérifie qu'une valeur par défaut convient au type du champ
+pub fn check_default(type_: &str, value: &str, info: &EntityInfo) -> Result<(), String> {
+    if let Some(enum_type) = find_enum(&info.enums, type_) {
+        if !enum_type.values().contains(&value.to_case(Case::Snake)) {
+            return Err(format!(
+                "Invalid default value {} for {}. Valid values are: {}",
+                value,
+                enum_type.name,
+                enum_type.variants.join(", ")
+            ));
+        }
+        return Ok(());
+    }
+
+    let valid = match type_ {
+        "i32" | "i64" => value.parse::<i64>().is_ok(),
+        "u32" | "u64" | "usize" => value.parse::<u64>().is_ok(),
+        "f32" | "f64" | "Money" => value.parse::<f64>().is_ok(),
+        "bool" => matches!(value, "true" | "false"),
+        _ => true,
+    };
+    if !valid {
+        return Err(format!(
+            "Invalid default value {} for type {}",
+            value, type_
+        ));
+    }
+    Ok(())
+}
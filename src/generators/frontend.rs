@@ -1,5 +1,12 @@
 // src/generators/frontend.rs
+use crate::generators::entity::{
+    find_object, generic_argument, module_name, table_name, EntityInfo, RelationType,
+};
+use crate::generators::scalar;
 use colored::*;
+use convert_case::{Case, Casing};
+use std::fs;
+use std::path::Path;
 use std::process::Command;
 
 pub fn create_structure(project_name: &str) -> Result<(), String> {
@@ -31,3 +38,482 @@ pub fn create_structure(project_name: &str) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Génère les routes Remix (liste, détail, édition) d'une entité dans `frontend/app/routes`
+pub fn generate_routes(info: &EntityInfo) -> Result<Vec<String>, String> {
+    if !Path::new("frontend/app").exists() {
+        return Err("No Remix frontend found (frontend/app)".into());
+    }
+
+    fs::create_dir_all("frontend/app/routes")
+        .map_err(|e| format!("Failed to create routes directory: {}", e))?;
+    fs::create_dir_all("frontend/app/lib")
+        .map_err(|e| format!("Failed to create lib directory: {}", e))?;
+
+    let mut paths = Vec::new();
+    let client_path = "frontend/app/lib/graphql.server.ts";
+    if !Path::new(client_path).exists() {
+        fs::write(client_path, GRAPHQL_CLIENT)
+            .map_err(|e| format!("Failed to write GraphQL client: {}", e))?;
+        paths.push(client_path.to_string());
+    }
+
+    let table = table_name(&info.name);
+    let routes = [
+        (format!("{}._index.tsx", table), generate_index_route(info)),
+        (format!("{}.$id.tsx", table), generate_detail_route(info)),
+        (
+            format!("{}.$id_.edit.tsx", table),
+            generate_edit_route(info),
+        ),
+    ];
+    for (file, content) in routes {
+        let path = format!("frontend/app/routes/{}", file);
+        fs::write(&path, content).map_err(|e| format!("Failed to write route {}: {}", path, e))?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+/// Client GraphQL partagé par les routes générées ; par défaut, le backend décrit
+/// par `SERVER_HOST`/`SERVER_PORT` dans le `.env` du projet
+const GRAPHQL_CLIENT: &str = r#"// Generated by Nebulis CLI
+const GRAPHQL_URL =
+  process.env.GRAPHQL_URL ??
+  `http://${process.env.SERVER_HOST ?? "127.0.0.1"}:${process.env.SERVER_PORT ?? "8080"}/graphql`;
+
+export async function graphql<T>(
+  query: string,
+  variables: Record<string, unknown> = {},
+): Promise<T> {
+  const response = await fetch(GRAPHQL_URL, {
+    method: "POST",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify({ query, variables }),
+  });
+  const { data, errors } = await response.json();
+  if (errors?.length) {
+    const message = errors.map((error: { message: string }) => error.message).join("\n");
+    throw new Response(message, { status: 500 });
+  }
+  return data as T;
+}
+"#;
+
+/// Champs scalaires de l'entité, sélectionnables directement dans une requête GraphQL
+fn scalar_fields(info: &EntityInfo) -> Vec<String> {
+    info.fields
+        .iter()
+        .filter(|(_, type_, _)| {
+            find_object(&info.objects, type_).is_none() && !scalar::is_password(type_)
+        })
+        .map(|(name, _, _)| name.to_case(Case::Camel))
+        .collect()
+}
+
+/// Clés étrangères `belongsTo` de l'entité, exposées par GraphQL (`userId`)
+fn foreign_keys(info: &EntityInfo) -> Vec<String> {
+    info.relations
+        .iter()
+        .filter_map(|relation| match relation {
+            RelationType::BelongsTo(target) => {
+                Some(format!("{}_id", module_name(target)).to_case(Case::Camel))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Sélection GraphQL d'un champ, avec les sous-champs d'un objet imbriqué
+fn field_selection(name: &str, type_: &str, info: &EntityInfo) -> String {
+    let field = name.to_case(Case::Camel);
+    match find_object(&info.objects, type_) {
+        Some(object) => {
+            let fields = object
+                .fields
+                .iter()
+                .filter(|(_, type_, _)| !scalar::is_password(type_))
+                .map(|(name, type_, _)| field_selection(name, type_, info))
+                .collect::<Vec<_>>();
+            format!("{} {{ {} }}", field, fields.join(" "))
+        }
+        None => field,
+    }
+}
+
+/// Sélection GraphQL de l'entité : identifiant, champs (objets imbriqués compris)
+/// puis clés étrangères
+fn selection(info: &EntityInfo, indent: &str) -> String {
+    let fields = info
+        .fields
+        .iter()
+        .filter(|(_, type_, _)| !scalar::is_password(type_))
+        .map(|(name, type_, _)| field_selection(name, type_, info));
+    std::iter::once("base { id }".to_string())
+        .chain(fields)
+        .chain(foreign_keys(info))
+        .map(|field| format!("{}{}", indent, field))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Noms utilisés par les routes : type, module, champ GraphQL de la liste et du détail
+struct RouteNames {
+    pascal: String,
+    module: String,
+    list: String,
+    get: String,
+    update: String,
+    plural: String,
+}
+
+impl RouteNames {
+    fn new(info: &EntityInfo) -> Self {
+        let table = table_name(&info.name);
+        Self {
+            pascal: info.name.to_case(Case::Pascal),
+            module: module_name(&info.name),
+            list: format!("list_{}", table).to_case(Case::Camel),
+            get: format!("get_{}", module_name(&info.name)).to_case(Case::Camel),
+            update: format!("update_{}", module_name(&info.name)).to_case(Case::Camel),
+            plural: table.to_case(Case::Camel),
+        }
+    }
+}
+
+fn generate_index_route(info: &EntityInfo) -> String {
+    let names = RouteNames::new(info);
+    let label = scalar_fields(info)
+        .first()
+        .map(|field| format!("{}.{}", names.module.to_case(Case::Camel), field))
+        .unwrap_or_else(|| format!("{}.base.id", names.module.to_case(Case::Camel)));
+    let item = names.module.to_case(Case::Camel);
+
+    format!(
+        r#"// Generated by Nebulis CLI
+import {{ json }} from "@remix-run/node";
+import {{ Link, useLoaderData }} from "@remix-run/react";
+import {{ graphql }} from "~/lib/graphql.server";
+import type {{ {pascal} }} from "~/types/{module}";
+
+type {pascal}Row = {pascal} & {{ base: {{ id: string }} }};
+
+export async function loader() {{
+  const data = await graphql<{{ {list}: {pascal}Row[] }}>(`
+    query {{
+      {list} {{
+{selection}
+      }}
+    }}
+  `);
+  return json({{ {plural}: data.{list} }});
+}}
+
+export default function {pascal}Index() {{
+  const {{ {plural} }} = useLoaderData<typeof loader>();
+  return (
+    <main>
+      <h1>{pascal}</h1>
+      <ul>
+        {{{plural}.map(({item}) => (
+          <li key={{{item}.base.id}}>
+            <Link to={{encodeURIComponent({item}.base.id)}}>{{String({label})}}</Link>
+          </li>
+        ))}}
+      </ul>
+    </main>
+  );
+}}
+"#,
+        pascal = names.pascal,
+        module = names.module,
+        list = names.list,
+        plural = names.plural,
+        item = item,
+        label = label,
+        selection = selection(info, "        "),
+    )
+}
+
+fn generate_detail_route(info: &EntityInfo) -> String {
+    let names = RouteNames::new(info);
+    let rows = scalar_fields(info)
+        .iter()
+        .map(|field| {
+            format!(
+                "        <dt>{field}</dt>\n        <dd>{{String({item}.{field})}}</dd>",
+                field = field,
+                item = names.module.to_case(Case::Camel),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"// Generated by Nebulis CLI
+import {{ json, type LoaderFunctionArgs }} from "@remix-run/node";
+import {{ Link, useLoaderData }} from "@remix-run/react";
+import {{ graphql }} from "~/lib/graphql.server";
+import type {{ {pascal} }} from "~/types/{module}";
+
+type {pascal}Row = {pascal} & {{ base: {{ id: string }} }};
+
+export async function loader({{ params }}: LoaderFunctionArgs) {{
+  const data = await graphql<{{ {get}: {pascal}Row | null }}>(
+    `
+    query ($id: ID!) {{
+      {get}(id: $id) {{
+{selection}
+      }}
+    }}
+  `,
+    {{ id: params.id }},
+  );
+  if (!data.{get}) {{
+    throw new Response("Not Found", {{ status: 404 }});
+  }}
+  return json({{ {item}: data.{get} }});
+}}
+
+export default function {pascal}Detail() {{
+  const {{ {item} }} = useLoaderData<typeof loader>();
+  return (
+    <main>
+      <h1>{pascal}</h1>
+      <dl>
+{rows}
+      </dl>
+      <Link to="edit">Edit</Link>
+    </main>
+  );
+}}
+"#,
+        pascal = names.pascal,
+        module = names.module,
+        get = names.get,
+        item = names.module.to_case(Case::Camel),
+        rows = rows,
+        selection = selection(info, "        "),
+    )
+}
+
+/// Type numérique côté formulaire, optionnel ou non (`i32`, `Option<f64>`)
+fn is_number(type_: &str) -> bool {
+    let type_ = generic_argument(type_, "Option").unwrap_or_else(|| type_.to_string());
+    matches!(
+        type_.as_str(),
+        "i32" | "i64" | "u32" | "u64" | "usize" | "f32" | "f64"
+    )
+}
+
+/// Champ(s) du formulaire d'édition ; un objet imbriqué devient un `fieldset`
+/// dont les champs sont nommés `objet.champ`
+fn form_input(key: &str, value: &str, type_: &str, info: &EntityInfo, indent: &str) -> String {
+    let label = key.rsplit('.').next().unwrap_or(key);
+    if let Some(object) = find_object(&info.objects, type_) {
+        let inputs = object
+            .fields
+            .iter()
+            .map(|(name, type_, _)| {
+                let field = name.to_case(Case::Camel);
+                form_input(
+                    &format!("{}.{}", key, field),
+                    &format!("{}?.{}", value, field),
+                    type_,
+                    info,
+                    &format!("{}  ", indent),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        return format!(
+            "{indent}<fieldset>\n{indent}  <legend>{label}</legend>\n{inputs}\n{indent}</fieldset>"
+        );
+    }
+
+    let required = generic_argument(type_, "Option").unwrap_or_else(|| type_.to_string());
+    let input = match type_ {
+        // Une liste est saisie séparée par des virgules
+        _ if generic_argument(&required, "Vec").is_some() => format!(
+            "<input type=\"text\" name=\"{key}\" defaultValue={{({value} ?? []).join(\", \")}} />"
+        ),
+        _ if required == "Json" => format!(
+            "<textarea name=\"{key}\" defaultValue={{JSON.stringify({value} ?? null, null, 2)}} />"
+        ),
+        "bool" | "Option<bool>" => format!(
+            "<input type=\"checkbox\" name=\"{key}\" defaultChecked={{{value} ?? false}} />"
+        ),
+        // Le hash n'est jamais renvoyé : le mot de passe est ressaisi
+        type_ if scalar::is_password(type_) => {
+            format!("<input type=\"password\" name=\"{key}\" />")
+        }
+        type_ => format!(
+            "<input type=\"{}\" name=\"{key}\" defaultValue={{String({value} ?? \"\")}} />",
+            if is_number(type_) { "number" } else { "text" }
+        ),
+    };
+    format!("{indent}<label>\n{indent}  {label}\n{indent}  {input}\n{indent}</label>")
+}
+
+/// Valeur envoyée dans l'Input GraphQL pour un champ du formulaire ; un champ
+/// optionnel laissé vide vaut `null`
+fn form_value(key: &str, type_: &str, info: &EntityInfo, indent: &str) -> String {
+    if let Some(object) = find_object(&info.objects, type_) {
+        let values = object
+            .fields
+            .iter()
+            .map(|(name, type_, _)| {
+                let field = name.to_case(Case::Camel);
+                let value = form_value(
+                    &format!("{}.{}", key, field),
+                    type_,
+                    info,
+                    &format!("{}  ", indent),
+                );
+                format!("{}  {}: {},", indent, field, value)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        return format!("{{\n{}\n{}}}", values, indent);
+    }
+
+    let optional = generic_argument(type_, "Option").is_some();
+    let required = generic_argument(type_, "Option").unwrap_or_else(|| type_.to_string());
+    if let Some(item) = generic_argument(&required, "Vec") {
+        let items = format!(
+            "String(form.get(\"{}\") ?? \"\").split(\",\").map((item) => item.trim()).filter(Boolean)",
+            key
+        );
+        return if is_number(&item) {
+            format!("{}.map(Number)", items)
+        } else {
+            items
+        };
+    }
+
+    match type_ {
+        _ if required == "Json" && optional => format!(
+            "form.get(\"{0}\") ? JSON.parse(String(form.get(\"{0}\"))) : null",
+            key
+        ),
+        "Json" => format!("JSON.parse(String(form.get(\"{}\")))", key),
+        "bool" | "Option<bool>" => format!("form.get(\"{}\") === \"on\"", key),
+        type_ if is_number(type_) && optional => {
+            format!("form.get(\"{0}\") ? Number(form.get(\"{0}\")) : null", key)
+        }
+        type_ if is_number(type_) => format!("Number(form.get(\"{}\"))", key),
+        _ if optional => format!("form.get(\"{}\") || null", key),
+        _ => format!("form.get(\"{}\")", key),
+    }
+}
+
+pub fn generate_edit_route(info: &EntityInfo) -> String {
+    let names = RouteNames::new(info);
+    let item = names.module.to_case(Case::Camel);
+    let keys = foreign_keys(info);
+    let inputs = info
+        .fields
+        .iter()
+        .map(|(name, type_, _)| {
+            let field = name.to_case(Case::Camel);
+            let value = format!("{}.{}", item, field);
+            form_input(&field, &value, type_, info, "        ")
+        })
+        .chain(
+            keys.iter()
+                .map(|key| form_input(key, &format!("{}.{}", item, key), "ID", info, "        ")),
+        )
+        .collect::<Vec<_>>()
+        .join("\n");
+    let values = info
+        .fields
+        .iter()
+        .map(|(name, type_, _)| {
+            let field = name.to_case(Case::Camel);
+            format!(
+                "    {}: {},",
+                field,
+                form_value(&field, type_, info, "    ")
+            )
+        })
+        .chain(
+            keys.iter()
+                .map(|key| format!("    {}: form.get(\"{}\"),", key, key)),
+        )
+        .collect::<Vec<_>>()
+        .join("\n");
+    let row_keys = keys
+        .iter()
+        .map(|key| format!("; {}: string | null", key))
+        .collect::<String>();
+
+    format!(
+        r#"// Generated by Nebulis CLI
+import {{ json, redirect, type ActionFunctionArgs, type LoaderFunctionArgs }} from "@remix-run/node";
+import {{ Form, useLoaderData }} from "@remix-run/react";
+import {{ graphql }} from "~/lib/graphql.server";
+import type {{ {pascal} }} from "~/types/{module}";
+
+type {pascal}Row = {pascal} & {{ base: {{ id: string }}{row_keys} }};
+
+export async function loader({{ params }}: LoaderFunctionArgs) {{
+  const data = await graphql<{{ {get}: {pascal}Row | null }}>(
+    `
+    query ($id: ID!) {{
+      {get}(id: $id) {{
+{selection}
+      }}
+    }}
+  `,
+    {{ id: params.id }},
+  );
+  if (!data.{get}) {{
+    throw new Response("Not Found", {{ status: 404 }});
+  }}
+  return json({{ {item}: data.{get} }});
+}}
+
+export async function action({{ params, request }}: ActionFunctionArgs) {{
+  const form = await request.formData();
+  const input = {{
+{values}
+  }};
+  await graphql(
+    `
+    mutation ($id: ID!, $input: {pascal}Input!) {{
+      {update}(id: $id, input: $input) {{
+        base {{ id }}
+      }}
+    }}
+  `,
+    {{ id: params.id, input }},
+  );
+  return redirect(`/{table}/${{encodeURIComponent(params.id!)}}`);
+}}
+
+export default function {pascal}Edit() {{
+  const {{ {item} }} = useLoaderData<typeof loader>();
+  return (
+    <main>
+      <h1>Edit {pascal}</h1>
+      <Form method="post">
+{inputs}
+        <button type="submit">Save</button>
+      </Form>
+    </main>
+  );
+}}
+"#,
+        pascal = names.pascal,
+        module = names.module,
+        get = names.get,
+        update = names.update,
+        table = table_name(&info.name),
+        item = item,
+        values = values,
+        inputs = inputs,
+        row_keys = row_keys,
+        selection = selection(info, "        "),
+    )
+}
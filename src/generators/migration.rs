@@ -1,103 +1,24 @@
-use chrono::Utc;
+use crate::db::migrator::{MigrationState, Plan, RollbackTarget, TransactionMode};
+use crate::db::schema::{self, FieldDef, IndexDef, Schema, TableSchema, TIMESTAMP_FIELDS};
+use crate::db::{self, ledger, migrator, DbConfig};
+use crate::generators::entity::{entity_path, module_name, table_name, EntityInfo, RelationType};
+use chrono::{Duration, NaiveDateTime, Timelike, Utc};
 use colored::*;
 use convert_case::{Case, Casing};
-use regex::Regex;
 use std::fs;
 use std::path::Path;
 
-/// Structure pour stocker les informations du modèle
-#[derive(Debug)]
-struct EntityInfo {
-    fields: Vec<(String, String, FieldValidation)>,
-    relations: Vec<RelationType>,
-}
-
-#[derive(Debug, Clone)]
-struct FieldValidation {
-    required: bool,
-    unique: bool,
-    email: bool,
-    url: bool,
-    min_length: Option<usize>,
-    max_length: Option<usize>,
-    pattern: Option<String>,
-    min: Option<String>,
-    max: Option<String>,
-}
-
-impl Default for FieldValidation {
-    fn default() -> Self {
-        Self {
-            required: false,
-            unique: false,
-            email: false,
-            url: false,
-            min_length: None,
-            max_length: None,
-            pattern: None,
-            min: None,
-            max: None,
-        }
-    }
-}
-
-#[derive(Debug, Clone)]
-enum RelationType {
-    HasOne(String),
-    HasMany(String),
-    BelongsTo(String),
-}
+/// Format des versions préfixant les fichiers de migration
+const VERSION_FORMAT: &str = "%Y%m%d%H%M%S";
 
 #[derive(Debug)]
-enum MigrationOperation {
+pub enum MigrationOperation {
     CreateTable(String),
-    AddColumn(String, String, String),    // table, column, type
-    RemoveColumn(String, String),         // table, column
-    RenameColumn(String, String, String), // table, old_name, new_name
-    AddIndex(String, Vec<String>),        // table, columns
-    AddRelation(String, String, String),  // from_table, to_table, type
-}
-
-impl EntityInfo {
-    fn parse_from_file(entity_path: &str) -> Result<Self, String> {
-        let content = fs::read_to_string(entity_path)
-            .map_err(|e| format!("Could not read entity file: {}", e))?;
-
-        let mut fields = Vec::new();
-        let mut relations = Vec::new();
-
-        // Regex pour capturer les validations et les champs
-        let field_regex =
-            Regex::new(r"(?m)(?s)#\[validate\((.*?)\)\].*?pub\s+(\w+)\s*:\s*(\w+)").unwrap();
-
-        for cap in field_regex.captures_iter(&content) {
-            let validations = cap.get(1).map(|m| m.as_str()).unwrap_or("");
-            let field_name = cap.get(2).map(|m| m.as_str()).unwrap_or("");
-            let field_type = cap.get(3).map(|m| m.as_str()).unwrap_or("");
-
-            // Ignorer les champs système
-            if ["id", "created_at", "updated_at", "deleted_at"].contains(&field_name) {
-                continue;
-            }
-
-            let mut validation = FieldValidation::default();
-
-            // Parser les validations
-            for val in validations.split(',').map(str::trim) {
-                match val {
-                    "required" => validation.required = true,
-                    "unique" => validation.unique = true,
-                    "email" => validation.email = true,
-                    "url" => validation.url = true,
-                    _ => {}
-                }
-            }
-
-            fields.push((field_name.to_string(), field_type.to_string(), validation));
-        }
-
-        Ok(EntityInfo { fields, relations })
-    }
+    AddColumn(String, String, FieldDef), // table, column, definition
+    RemoveColumn(String, String, FieldDef, bool), // table, column, current definition, backup
+    RenameColumn(String, String, String, FieldDef), // table, old_name, new_name, current definition
+    AddIndex(String, Vec<String>),       // table, columns
+    AddRelation(String, String, String), // from_table, to_table, type
 }
 
 impl MigrationOperation {
@@ -106,19 +27,23 @@ impl MigrationOperation {
 
         match name.split('_').collect::<Vec<&str>>().as_slice() {
             // Formats explicites
-            ["create", table] => Some(Self::CreateTable(table.to_string())),
+            ["create", table @ ..] if !table.is_empty() => Some(Self::CreateTable(table.join("_"))),
             ["add", field, "to", table] => Some(Self::AddColumn(
                 table.to_string(),
                 field.to_string(),
-                "String".to_string(),
+                FieldDef::new("string"),
+            )),
+            ["remove", field, "from", table] => Some(Self::RemoveColumn(
+                table.to_string(),
+                field.to_string(),
+                FieldDef::new("string"),
+                false,
             )),
-            ["remove", field, "from", table] => {
-                Some(Self::RemoveColumn(table.to_string(), field.to_string()))
-            }
             ["rename", old_name, "to", new_name, "on", table] => Some(Self::RenameColumn(
                 table.to_string(),
                 old_name.to_string(),
                 new_name.to_string(),
+                FieldDef::new("string"),
             )),
             ["add", "index", "on", table, "fields", fields @ ..] => Some(Self::AddIndex(
                 table.to_string(),
@@ -129,124 +54,179 @@ impl MigrationOperation {
                 to_table.to_string(),
                 "BelongsTo".to_string(),
             )),
-            // Format simple - juste le nom de la table
-            [table] => {
-                // Si le nom est au singulier, on le convertit en pluriel
-                let table_name = if table.ends_with('s') {
-                    table.to_string()
-                } else {
-                    format!("{}s", table)
-                };
-                Some(Self::CreateTable(table_name))
-            }
+            // Format simple - juste le nom de la table, mis au pluriel
+            [table] => Some(Self::CreateTable(table_name(table))),
             _ => None,
         }
     }
 
-    fn up_sql(&self) -> String {
+    /// Fichier d'entité existant d'une table
+    fn entity_file(table: &str) -> Option<String> {
+        entity_candidates(table)
+            .into_iter()
+            .find(|path| Path::new(path).exists())
+    }
+
+    /// Entité correspondant à une table et le schéma qui en découle
+    fn entity_schema(table: &str) -> (EntityInfo, TableSchema) {
+        let entity_info = Self::entity_file(table)
+            .and_then(|path| EntityInfo::parse_from_file(&path).ok())
+            .unwrap_or_default();
+        let schema = TableSchema::from_entity(table, &entity_info);
+        (entity_info, schema)
+    }
+
+    /// Reporte l'opération sur le snapshot ; les tables inconnues du snapshot sont ignorées
+    fn apply_to(&self, snapshot: &mut Schema) {
+        if let Self::CreateTable(table) = self {
+            let table = table.to_lowercase();
+            let (entity_info, table_schema) = Self::entity_schema(&table);
+            snapshot
+                .tables
+                .extend(TableSchema::edges_of(&table, &entity_info));
+            snapshot.tables.insert(table, table_schema);
+            return;
+        }
+
+        let table = match self {
+            Self::CreateTable(table)
+            | Self::AddColumn(table, ..)
+            | Self::RemoveColumn(table, ..)
+            | Self::RenameColumn(table, ..)
+            | Self::AddIndex(table, _)
+            | Self::AddRelation(table, ..) => table.to_lowercase(),
+        };
+        let Some(schema) = snapshot.tables.get_mut(&table) else {
+            return;
+        };
+
         match self {
-            Self::CreateTable(name) => {
-                let entity_path = format!(
-                    "backend/src/entities/{}.rs",
-                    name.trim_end_matches('s').to_lowercase()
+            Self::CreateTable(_) => {}
+            Self::AddColumn(_, column, field) => {
+                schema.fields.insert(column.to_lowercase(), field.clone());
+            }
+            Self::RemoveColumn(_, column, ..) => {
+                schema.fields.remove(&column.to_lowercase());
+            }
+            Self::RenameColumn(_, old_name, new_name, field) => {
+                schema.fields.remove(&old_name.to_lowercase());
+                schema.fields.insert(new_name.to_lowercase(), field.clone());
+            }
+            Self::AddIndex(_, columns) => {
+                let columns = columns.iter().map(|c| c.to_lowercase()).collect::<Vec<_>>();
+                schema.indexes.insert(
+                    format!("idx_{}_{}", table, columns.join("_")),
+                    IndexDef {
+                        fields: columns,
+                        unique: false,
+                    },
                 );
+            }
+            Self::AddRelation(_, to_table, _) => {
+                let (key, field) = relation_key(to_table);
+                schema.indexes.insert(
+                    format!("idx_{table}_{}", to_table.to_lowercase()),
+                    IndexDef::new(&[&key], false),
+                );
+                schema.fields.insert(key, field);
+            }
+        }
+    }
 
-                let entity_info =
-                    EntityInfo::parse_from_file(&entity_path).unwrap_or_else(|_| EntityInfo {
-                        fields: Vec::new(),
-                        relations: Vec::new(),
-                    });
-
+    pub fn up_sql(&self) -> String {
+        match self {
+            Self::CreateTable(name) => {
                 let table_name = name.to_lowercase();
+                let (entity_info, schema) = Self::entity_schema(&table_name);
+                let define_field =
+                    |field: &str| schema.fields[field].define(&table_name, field, false);
                 let mut sections = Vec::new();
-                let mut seen_fields = std::collections::HashSet::new();
-                let mut seen_indexes = std::collections::HashSet::new();
 
                 // Table definition
                 sections.push(format!("DEFINE TABLE {table_name} SCHEMAFULL;"));
                 sections.push("".to_string());
 
-                // Base fields section
-                sections.push("// Champs système".to_string());
-                sections.push(format!("DEFINE FIELD id ON {table_name} TYPE ulid;"));
-                seen_fields.insert("id".to_string());
-                sections.push("".to_string());
-
                 // Entity fields section
                 sections.push("// Champs de l'entité".to_string());
-                for (field_name, field_type, _) in &entity_info.fields {
-                    if !seen_fields.insert(field_name.clone()) {
-                        continue; // Skip if field already defined
+                for (field_name, _, _) in &entity_info.fields {
+                    sections.push(define_field(field_name));
+                    // Champs des objets imbriqués (`address.street`)
+                    let prefix = format!("{field_name}.");
+                    for nested in schema.fields.keys().filter(|f| f.starts_with(&prefix)) {
+                        sections.push(define_field(nested));
                     }
-
-                    let surql_type = match field_type.as_str() {
-                        "String" => "string",
-                        "Email" => "string",
-                        "Phone" => "string",
-                        "i32" | "i64" => "int",
-                        "f32" | "f64" => "float",
-                        "bool" => "bool",
-                        "DateTime" => "datetime",
-                        _ => "string",
-                    };
-
-                    sections.push(format!(
-                        "DEFINE FIELD {field_name} ON {table_name} TYPE {surql_type};"
-                    ));
                 }
                 sections.push("".to_string());
 
                 // Timestamps section
                 sections.push("// Champs de timestamps".to_string());
-                for field in ["created_at", "updated_at", "deleted_at"] {
-                    if !seen_fields.insert(field.to_string()) {
-                        continue;
-                    }
-                    sections.push(format!(
-                        "DEFINE FIELD {field} ON {table_name} TYPE datetime \
-                         VALUE $before OR time::now();"
-                    ));
+                for field in TIMESTAMP_FIELDS {
+                    sections.push(define_field(field));
                 }
                 sections.push("".to_string());
 
+                // Relations section
+                let relation_fields = entity_info
+                    .relations
+                    .iter()
+                    .filter_map(|relation| match relation {
+                        RelationType::BelongsTo(target) => {
+                            Some(format!("{}_id", module_name(target)))
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                if !relation_fields.is_empty() {
+                    sections.push("// Relations".to_string());
+                    for field in &relation_fields {
+                        sections.push(define_field(field));
+                    }
+                    sections.push("".to_string());
+                }
+
                 // Indexes section
                 sections.push("// Indexes".to_string());
-
-                // Add id index
-                let id_index = format!("idx_{table_name}_id");
-                if seen_indexes.insert(id_index.clone()) {
-                    sections.push(format!(
-                        "DEFINE INDEX {id_index} ON {table_name} FIELDS id;"
-                    ));
+                for (index_name, index) in &schema.indexes {
+                    sections.push(index.define(&table_name, index_name, false));
                 }
 
-                // Timestamps indexes
-                for field in ["created", "updated", "deleted"] {
-                    let index_name = format!("idx_{table_name}_{field}");
-                    if seen_indexes.insert(index_name.clone()) {
-                        sections.push(format!(
-                            "DEFINE INDEX {index_name} ON {table_name} FIELDS {field}_at;"
-                        ));
+                // Graph edges section
+                let edges = TableSchema::edges_of(&table_name, &entity_info);
+                if !edges.is_empty() {
+                    sections.push("".to_string());
+                    sections.push("// Relations graphe".to_string());
+                    for (edge_name, edge) in &edges {
+                        sections.extend(edge.define(edge_name));
                     }
                 }
 
                 sections.join("\n")
             }
-            Self::AddColumn(table, column, type_) => format!(
-                "DEFINE FIELD {} ON {} TYPE {};",
-                column.to_lowercase(),
-                table.to_lowercase(),
-                type_.to_lowercase()
-            ),
-            Self::RemoveColumn(table, column) => format!(
-                "REMOVE FIELD {} ON {};",
-                column.to_lowercase(),
-                table.to_lowercase()
-            ),
-            Self::RenameColumn(table, old_name, new_name) => format!(
-                "DEFINE FIELD {new} ON {table} TYPE string;\n\
+            Self::AddColumn(table, column, field) => {
+                field.define(&table.to_lowercase(), &column.to_lowercase(), false)
+            }
+            Self::RemoveColumn(table, column, _, backup) => {
+                let table = table.to_lowercase();
+                let column = column.to_lowercase();
+                let mut statements = Vec::new();
+                if *backup {
+                    let backup_table = backup_table(&table, &column);
+                    statements.push(format!("DEFINE TABLE {backup_table} SCHEMALESS;"));
+                    statements.push(format!(
+                        "INSERT INTO {backup_table} \
+                         (SELECT id AS record, {column} AS value FROM {table} WHERE {column} != NONE);"
+                    ));
+                }
+                // REMOVE FIELD garde les valeurs stockées : elles sont effacées explicitement
+                statements.push(format!("UPDATE {table} UNSET {column};"));
+                statements.push(format!("REMOVE FIELD {column} ON {table};"));
+                statements.join("\n")
+            }
+            Self::RenameColumn(table, old_name, new_name, field) => format!(
+                "{define}\n\
                  UPDATE {table} SET {new} = {old};\n\
                  REMOVE FIELD {old} ON {table};",
+                define = field.define(&table.to_lowercase(), &new_name.to_lowercase(), false),
                 table = table.to_lowercase(),
                 old = old_name.to_lowercase(),
                 new = new_name.to_lowercase()
@@ -261,32 +241,55 @@ impl MigrationOperation {
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
-            Self::AddRelation(from_table, to_table, _) => format!(
-                "DEFINE FIELD {to}_id ON {from} TYPE record({to});\n\
-                 DEFINE INDEX idx_{from}_{to} ON {from} FIELDS {to}_id;",
-                from = from_table.to_lowercase(),
-                to = to_table.to_lowercase()
-            ),
+            Self::AddRelation(from_table, to_table, _) => {
+                let from = from_table.to_lowercase();
+                let (key, field) = relation_key(to_table);
+                format!(
+                    "{}\nDEFINE INDEX idx_{from}_{to} ON {from} FIELDS {key};",
+                    field.define(&from, &key, false),
+                    to = to_table.to_lowercase()
+                )
+            }
         }
     }
 
-    fn down_sql(&self) -> String {
+    pub fn down_sql(&self) -> String {
         match self {
-            Self::CreateTable(name) => format!("REMOVE TABLE {};", name.to_lowercase()),
+            Self::CreateTable(name) => {
+                let table = name.to_lowercase();
+                let (entity_info, _) = Self::entity_schema(&table);
+                let mut statements = TableSchema::edges_of(&table, &entity_info)
+                    .into_iter()
+                    .map(|(edge_name, _)| format!("REMOVE TABLE {};", edge_name))
+                    .collect::<Vec<_>>();
+                statements.push(format!("REMOVE TABLE {};", table));
+                statements.join("\n")
+            }
             Self::AddColumn(table, column, _) => format!(
                 "REMOVE FIELD {} ON {};",
                 column.to_lowercase(),
                 table.to_lowercase()
             ),
-            Self::RemoveColumn(table, column) => format!(
-                "DEFINE FIELD {} ON {} TYPE string;",
-                column.to_lowercase(),
-                table.to_lowercase()
-            ),
-            Self::RenameColumn(table, old_name, new_name) => format!(
-                "DEFINE FIELD {old} ON {table} TYPE string;\n\
+            Self::RemoveColumn(table, column, field, backup) => {
+                let table = table.to_lowercase();
+                let column = column.to_lowercase();
+                let mut statements = vec![field.define(&table, &column, false)];
+                if *backup {
+                    let backup_table = backup_table(&table, &column);
+                    statements.push(format!(
+                        "FOR $row IN (SELECT record, value FROM {backup_table}) {{\n    \
+                         UPDATE $row.record SET {column} = $row.value;\n\
+                         }};"
+                    ));
+                    statements.push(format!("REMOVE TABLE {backup_table};"));
+                }
+                statements.join("\n")
+            }
+            Self::RenameColumn(table, old_name, new_name, field) => format!(
+                "{define}\n\
                  UPDATE {table} SET {old} = {new};\n\
                  REMOVE FIELD {new} ON {table};",
+                define = field.define(&table.to_lowercase(), &old_name.to_lowercase(), false),
                 table = table.to_lowercase(),
                 old = old_name.to_lowercase(),
                 new = new_name.to_lowercase()
@@ -306,106 +309,495 @@ impl MigrationOperation {
     }
 }
 
-pub fn execute(name: &str) -> Result<(), String> {
+/// Clé étrangère `<to>_id` d'une relation `add_relation_<from>_to_<to>`, définie comme celle
+/// d'un `belongsTo` : elle pointe vers la table au pluriel de la cible
+fn relation_key(to_table: &str) -> (String, FieldDef) {
+    let to = to_table.to_lowercase();
+    let field = FieldDef::new(&format!("record<{}>", table_name(&to)));
+    (format!("{}_id", to), field)
+}
+
+/// Fichiers d'entité possibles pour une table, au singulier puis tel quel (`users` -> `user.rs`,
+/// `address` -> `address.rs`)
+pub fn entity_candidates(table: &str) -> Vec<String> {
+    let table = table.to_lowercase();
+    let singular = table.strip_suffix('s').unwrap_or(&table);
+    let mut candidates = vec![entity_path(singular)];
+    if singular != table {
+        candidates.push(entity_path(&table));
+    }
+    candidates
+}
+
+/// Table créée par une migration nommée `name`, si elle en crée une
+pub fn created_table(name: &str) -> Option<String> {
+    match MigrationOperation::from_name(name)? {
+        MigrationOperation::CreateTable(table) => Some(table),
+        _ => None,
+    }
+}
+
+/// Options de `nebulis generate migration`
+#[derive(Debug, Default)]
+pub struct MigrationOptions {
+    /// Diff des entités avec le snapshot (`--from-entities`)
+    pub from_entities: bool,
+    /// Diff avec la base réelle plutôt que le snapshot (`--live`)
+    pub live: bool,
+    /// `--type`, `--default` et `--assert` d'une migration `add_<field>_to_<table>`
+    pub type_: Option<String>,
+    pub default: Option<String>,
+    pub assert: Option<String>,
+    /// Sauvegarde les valeurs d'une colonne supprimée (`--backup`)
+    pub backup: bool,
+}
+
+impl MigrationOptions {
+    fn has_column_options(&self) -> bool {
+        self.type_.is_some() || self.default.is_some() || self.assert.is_some()
+    }
+}
+
+pub fn execute(name: &str, options: &MigrationOptions) -> Result<(), String> {
     println!("{} {}", "Generating migration:".blue(), name);
 
-    let operation = MigrationOperation::from_name(name)
-        .ok_or_else(|| format!("Invalid migration name format: {}", name))?;
+    if options.from_entities {
+        return execute_from_entities(name, options.live);
+    }
 
-    let filename = format!("{}", name.to_case(Case::Snake));
+    let root = Path::new(".");
+    let mut snapshot = Schema::load_snapshot(root)?;
+    let operation = operation(name, options, &snapshot)?;
 
-    generate_migration_files(&filename, &operation)?;
-    update_migrations_mod(&filename)?;
+    generate_migration_files(root, name, &operation.up_sql(), &operation.down_sql())?;
 
-    println!("{} Migration files generated:", "✓".green());
-    println!("  - database/schema/{}.up.surql", filename);
-    println!("  - database/schema/{}.down.surql", filename);
-    println!("  - backend/src/migrations/{}.rs", filename);
+    // Le dernier schéma connu suit les migrations générées
+    let previous = snapshot.clone();
+    operation.apply_to(&mut snapshot);
+    if snapshot != previous {
+        snapshot.save_snapshot(root)?;
+    }
 
     Ok(())
 }
 
-pub fn execute_pending_migrations() -> Result<(), String> {
-    let migrations_dir = "backend/src/migrations";
-    if !Path::new(migrations_dir).exists() {
-        return Err("No migrations directory found".into());
+/// Opération décrite par le nom d'une migration, complétée par ses options et par la définition
+/// actuelle des colonnes supprimées ou renommées
+pub fn operation(
+    name: &str,
+    options: &MigrationOptions,
+    snapshot: &Schema,
+) -> Result<MigrationOperation, String> {
+    let mut operation = MigrationOperation::from_name(name)
+        .ok_or_else(|| format!("Invalid migration name format: {}", name))?;
+
+    if options.has_column_options() && !matches!(operation, MigrationOperation::AddColumn(..)) {
+        return Err(
+            "--type, --default and --assert only apply to add_<field>_to_<table> migrations".into(),
+        );
+    }
+    if options.backup && !matches!(operation, MigrationOperation::RemoveColumn(..)) {
+        return Err("--backup only applies to remove_<field>_from_<table> migrations".into());
+    }
+
+    match &mut operation {
+        MigrationOperation::AddColumn(table, column, field) => {
+            *field = column_definition(table, column, options);
+        }
+        MigrationOperation::RemoveColumn(table, column, field, backup) => {
+            *field = current_definition(snapshot, table, column);
+            *backup = options.backup;
+        }
+        MigrationOperation::RenameColumn(table, old_name, _, field) => {
+            *field = current_definition(snapshot, table, old_name);
+        }
+        _ => {}
+    }
+
+    Ok(operation)
+}
+
+/// Définition d'une colonne ajoutée : `--type` ou, à défaut, le type du champ de l'entité
+fn column_definition(table: &str, column: &str, options: &MigrationOptions) -> FieldDef {
+    let table = table.to_lowercase();
+    let column = column.to_lowercase();
+
+    let mut field = match &options.type_ {
+        Some(type_) => FieldDef::new(type_),
+        None => match MigrationOperation::entity_schema(&table)
+            .1
+            .fields
+            .remove(&column)
+        {
+            Some(field) => {
+                if let Some(path) = MigrationOperation::entity_file(&table) {
+                    println!("  Using {} from {}", field.kind, path);
+                }
+                field
+            }
+            None => FieldDef::new("string"),
+        },
+    };
+
+    if options.default.is_some() {
+        field.default = options.default.clone();
+    }
+    if options.assert.is_some() {
+        field.assert = options.assert.clone();
+    }
+    field
+}
+
+/// Définition actuelle d'une colonne, lue dans le snapshot puis dans l'entité,
+/// pour que la migration `down` la recrée à l'identique
+fn current_definition(snapshot: &Schema, table: &str, column: &str) -> FieldDef {
+    let table = table.to_lowercase();
+    let column = column.to_lowercase();
+
+    if let Some(field) = snapshot
+        .tables
+        .get(&table)
+        .and_then(|schema| schema.fields.get(&column))
+    {
+        return field.clone();
+    }
+    if let Some(field) = MigrationOperation::entity_schema(&table)
+        .1
+        .fields
+        .remove(&column)
+    {
+        return field;
+    }
+
+    println!(
+        "{} No definition found for {}.{}, the down migration recreates it as string",
+        "Warning:".yellow(),
+        table,
+        column
+    );
+    FieldDef::new("string")
+}
+
+/// Table recevant les valeurs d'une colonne supprimée avec `--backup`
+fn backup_table(table: &str, column: &str) -> String {
+    format!("_nebulis_backup_{}_{}", table, column)
+}
+
+/// Génère une migration à partir de l'écart entre les entités et le snapshot
+/// (ou le schéma réel de la base avec `live`)
+fn execute_from_entities(name: &str, live: bool) -> Result<(), String> {
+    execute_to_schema(name, &Schema::from_entities(Path::new("."))?, live)
+}
+
+/// Génère la migration qui amène le snapshot (ou la base avec `live`) au schéma `target`
+pub fn execute_to_schema(name: &str, target: &Schema, live: bool) -> Result<(), String> {
+    let root = Path::new(".");
+    let current = if live {
+        let config = DbConfig::load(root)?;
+        db::block_on(async {
+            let db = db::connect(&config).await?;
+            Schema::from_database(&db).await
+        })??
+    } else {
+        Schema::load_snapshot(root)?
+    };
+
+    let diff = schema::diff(&current, target);
+    if diff.is_empty() {
+        println!("  Schema is up to date, no migration generated");
+        return Ok(());
     }
 
-    // TODO: Implémenter la logique d'exécution des migrations
+    generate_migration_files(root, name, &diff.up.join("\n"), &diff.down.join("\n"))?;
+    target.save_snapshot(root)?;
+    println!("  - {} (updated)", schema::SNAPSHOT_PATH);
+
     Ok(())
 }
 
-pub fn list_migrations() -> Result<(), String> {
-    let migrations_dir = "backend/src/migrations";
-    if !Path::new(migrations_dir).exists() {
-        return Err("No migrations directory found".into());
+pub fn execute_pending_migrations(mode: TransactionMode, to: Option<&str>) -> Result<(), String> {
+    let root = Path::new(".");
+    if let Some(to) = to {
+        migrator::check_target(root, to)?;
     }
+    let config = DbConfig::load(root)?;
 
-    let entries = fs::read_dir(migrations_dir)
-        .map_err(|e| format!("Failed to read migrations directory: {}", e))?;
+    let (rolled_back, applied) = db::block_on(async {
+        let db = db::connect(&config).await?;
+        migrator::migrate(&db, root, mode, to).await
+    })??;
 
-    let mut migrations = Vec::new();
-    for entry in entries {
-        if let Ok(entry) = entry {
-            let file_name = entry.file_name();
-            let file_name = file_name.to_string_lossy();
-            if file_name != "mod.rs" && file_name.ends_with(".rs") {
-                migrations.push(file_name.to_string());
-            }
+    if rolled_back.is_empty() && applied.is_empty() {
+        println!("  Nothing to migrate");
+    }
+
+    Ok(())
+}
+
+pub fn preview_migrations(to: Option<&str>) -> Result<(), String> {
+    let root = Path::new(".");
+    if let Some(to) = to {
+        migrator::check_target(root, to)?;
+    }
+
+    // Même plan que `migrator::migrate` ; sans base joignable, rien n'est supposé appliqué
+    match read_ledger(root) {
+        Ok(applied) => {
+            let (down, up) = migrator::plan_to(root, to, &applied)?;
+            print_plan(LEDGER_READ, &[("↓", down), ("↑", up)]);
+        }
+        Err(e) => {
+            println!("  {}", format!("Dry run: {}", e).yellow());
+            print_plan(
+                "Dry run: ledger not consulted, assuming an empty database",
+                &[("↑", migrator::plan_up(root, to, &[])?)],
+            );
         }
     }
+    Ok(())
+}
+
+/// Migrations appliquées d'après le ledger de la base configurée
+fn read_ledger(root: &Path) -> Result<Vec<ledger::LedgerEntry>, String> {
+    let config = DbConfig::load(root)?;
+    db::block_on(async {
+        let db = db::connect(&config).await?;
+        ledger::entries(&db).await
+    })?
+}
+
+pub fn list_migrations() -> Result<(), String> {
+    let root = Path::new(".");
+    let config = DbConfig::load(root)?;
+    let migrations = migrator::discover(root)?;
+
+    let applied = db::block_on(async {
+        let db = db::connect(&config).await?;
+        ledger::entries(&db).await
+    })??;
 
-    migrations.sort();
     for migration in migrations {
-        println!("  - {}", migration.trim_end_matches(".rs"));
+        match applied
+            .iter()
+            .find(|entry| entry.version == migration.version)
+        {
+            Some(entry) => println!(
+                "  {} {} (applied {})",
+                "✓".green(),
+                migration.name,
+                entry.applied_at
+            ),
+            None => println!("  {} {} (pending)", "-".yellow(), migration.name),
+        }
+    }
+
+    Ok(())
+}
+
+pub fn show_status() -> Result<(), String> {
+    let root = Path::new(".");
+    let config = DbConfig::load(root)?;
+
+    let applied = db::block_on(async {
+        let db = db::connect(&config).await?;
+        ledger::entries(&db).await
+    })??;
+
+    let rows = migrator::status(root, &applied)?;
+    println!(
+        "  {:<16} {:<14} {:<32} {}",
+        "VERSION".bold(),
+        "STATE".bold(),
+        "APPLIED AT".bold(),
+        "NAME".bold()
+    );
+
+    for row in &rows {
+        let state = match row.state {
+            MigrationState::Applied => format!("{:<14}", "applied").green(),
+            MigrationState::Pending => format!("{:<14}", "pending").yellow(),
+            MigrationState::MissingFile => format!("{:<14}", "missing-file").red(),
+        };
+        println!(
+            "  {:<16} {} {:<32} {}",
+            row.version,
+            state,
+            row.applied_at.as_deref().unwrap_or("-"),
+            row.name
+        );
+    }
+
+    for row in rows.iter().filter(|row| row.drifted) {
+        println!(
+            "{} {} was modified after being applied (checksum mismatch)",
+            "Warning:".yellow(),
+            row.name
+        );
     }
 
     Ok(())
 }
 
-pub fn rollback_migrations(_steps: u32) -> Result<(), String> {
-    let migrations_dir = "backend/src/migrations";
-    if !Path::new(migrations_dir).exists() {
-        return Err("No migrations directory found".into());
+pub fn rollback_migrations(target: &RollbackTarget) -> Result<(), String> {
+    let root = Path::new(".");
+    if let RollbackTarget::To(to) = target {
+        migrator::check_target(root, to)?;
+    }
+    let config = DbConfig::load(root)?;
+
+    let rolled_back = db::block_on(async {
+        let db = db::connect(&config).await?;
+        migrator::rollback(&db, root, target).await
+    })??;
+
+    if rolled_back.is_empty() {
+        println!("  Nothing to rollback");
+    }
+
+    Ok(())
+}
+
+pub fn reset_database(drop: bool) -> Result<(), String> {
+    let root = Path::new(".");
+    let config = DbConfig::load(root)?;
+
+    db::block_on(async {
+        let db = db::connect(&config).await?;
+
+        if drop {
+            db::drop_database(&db, &config).await?;
+            println!("  {} Database {} removed", "✓".green(), config.database);
+        } else {
+            migrator::rollback(&db, root, &RollbackTarget::All).await?;
+        }
+
+        migrator::apply_pending(&db, root, TransactionMode::PerMigration, None).await
+    })??;
+
+    Ok(())
+}
+
+pub fn preview_rollback(target: &RollbackTarget) -> Result<(), String> {
+    let root = Path::new(".");
+    if let RollbackTarget::To(to) = target {
+        migrator::check_target(root, to)?;
     }
 
-    // TODO: Implémenter la logique de rollback
+    let applied =
+        read_ledger(root).map_err(|e| format!("{} (a rollback dry run needs the ledger)", e))?;
+    print_plan(
+        LEDGER_READ,
+        &[("↓", migrator::plan_down(root, target, &applied)?)],
+    );
     Ok(())
 }
 
-fn generate_migration_files(filename: &str, operation: &MigrationOperation) -> Result<(), String> {
+/// En-tête d'un dry-run dont le plan a été calculé d'après le ledger
+const LEDGER_READ: &str = "Dry run: plan computed from the ledger, nothing is executed";
+
+/// Affiche les migrations d'un dry-run et le SurrealQL qu'elles exécuteraient
+fn print_plan(header: &str, steps: &[(&str, Plan)]) {
+    println!("  {}", header.yellow());
+    if steps.iter().all(|(_, plan)| plan.is_empty()) {
+        println!("  Nothing to run");
+    }
+
+    for (direction, plan) in steps {
+        for (migration, sql) in plan {
+            println!(
+                "\n  {} {}",
+                direction.blue(),
+                migrator::file_stem(&migration.version, &migration.name).bold()
+            );
+            for line in sql.lines() {
+                println!("    {}", line);
+            }
+        }
+    }
+}
+
+/// Version d'une nouvelle migration : l'heure courante, ou la seconde qui suit la plus récente
+/// migration de `database/schema` si celle-ci n'est pas antérieure (deux migrations générées
+/// dans la même seconde)
+fn next_version(schema_dir: &Path) -> String {
+    let now = Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let latest = fs::read_dir(schema_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            NaiveDateTime::parse_from_str(file_name.get(..14)?, VERSION_FORMAT).ok()
+        })
+        .max();
+
+    match latest {
+        Some(latest) if latest >= now => latest + Duration::seconds(1),
+        _ => now,
+    }
+    .format(VERSION_FORMAT)
+    .to_string()
+}
+
+/// Écrit les fichiers `.up.surql`, `.down.surql` et le module Rust d'une nouvelle migration
+pub fn generate_migration_files(
+    root: &Path,
+    name: &str,
+    up_sql: &str,
+    down_sql: &str,
+) -> Result<(), String> {
+    let schema_dir = root.join("database/schema");
+    let migrations_dir = root.join("backend/src/migrations");
+    let version = next_version(&schema_dir);
+    let name = name.to_case(Case::Snake);
+    let filename = migrator::file_stem(&version, &name);
+    let module_name = format!("m{}", filename);
+
     // Créer les répertoires nécessaires
-    fs::create_dir_all("database/schema")
+    fs::create_dir_all(&schema_dir)
         .map_err(|e| format!("Failed to create schema directory: {}", e))?;
-    fs::create_dir_all("backend/src/migrations")
+    fs::create_dir_all(&migrations_dir)
         .map_err(|e| format!("Failed to create migrations directory: {}", e))?;
 
+    // Ne jamais écraser une migration existante
+    let up_path = schema_dir.join(format!("{}.up.surql", filename));
+    let down_path = schema_dir.join(format!("{}.down.surql", filename));
+    let rust_path = migrations_dir.join(format!("{}.rs", module_name));
+    for path in [&up_path, &down_path, &rust_path] {
+        if path.exists() {
+            return Err(format!("Migration already exists: {}", path.display()));
+        }
+    }
+
     // Générer les fichiers SQL
-    fs::write(
-        format!("database/schema/{}.up.surql", filename),
-        operation.up_sql(),
-    )
-    .map_err(|e| format!("Failed to write up migration: {}", e))?;
-
-    fs::write(
-        format!("database/schema/{}.down.surql", filename),
-        operation.down_sql(),
-    )
-    .map_err(|e| format!("Failed to write down migration: {}", e))?;
+    fs::write(&up_path, up_sql).map_err(|e| format!("Failed to write up migration: {}", e))?;
+
+    fs::write(&down_path, down_sql)
+        .map_err(|e| format!("Failed to write down migration: {}", e))?;
 
     // Générer le fichier de migration Rust
-    let migration_content = generate_migration_rust(filename)?;
-    fs::write(
-        format!("backend/src/migrations/{}.rs", filename),
-        migration_content,
-    )
-    .map_err(|e| format!("Failed to write migration file: {}", e))?;
+    let migration_content = generate_migration_rust(&version, &name, &filename, &module_name)?;
+    fs::write(&rust_path, migration_content)
+        .map_err(|e| format!("Failed to write migration file: {}", e))?;
+
+    update_migrations_mod(&migrations_dir, &module_name)?;
+
+    println!("{} Migration files generated:", "✓".green());
+    for path in [&up_path, &down_path, &rust_path] {
+        let path = path.strip_prefix(root).unwrap_or(path);
+        println!("  - {}", path.display());
+    }
 
     Ok(())
 }
 
-fn generate_migration_rust(filename: &str) -> Result<String, String> {
-    let class_name = filename.to_case(Case::Pascal);
+fn generate_migration_rust(
+    version: &str,
+    name: &str,
+    filename: &str,
+    module_name: &str,
+) -> Result<String, String> {
+    let class_name = module_name.to_case(Case::Pascal);
     Ok(format!(
         r#"use async_trait::async_trait;
 use surrealdb::Surreal;
@@ -439,22 +831,22 @@ impl Migration for {class_name} {{
 }}
 "#,
         class_name = class_name,
-        version = Utc::now().format("%Y%m%d%H%M%S").to_string(),
-        name = filename,
+        version = version,
+        name = name,
         filename = filename
     ))
 }
 
-fn update_migrations_mod(filename: &str) -> Result<(), String> {
-    let mod_path = "backend/src/migrations/mod.rs";
-    let mut content = fs::read_to_string(mod_path).unwrap_or_else(|_| String::new());
+fn update_migrations_mod(migrations_dir: &Path, module_name: &str) -> Result<(), String> {
+    let mod_path = migrations_dir.join("mod.rs");
+    let mut content = fs::read_to_string(&mod_path).unwrap_or_else(|_| String::new());
 
-    if !content.contains(&format!("pub mod {};", filename)) {
+    if !content.contains(&format!("pub mod {};", module_name)) {
         if !content.is_empty() {
             content.push('\n');
         }
-        content.push_str(&format!("pub mod {};\n", filename));
-        content.push_str(&format!("pub use {}::*;\n", filename));
+        content.push_str(&format!("pub mod {};\n", module_name));
+        content.push_str(&format!("pub use {}::*;\n", module_name));
     }
 
     fs::write(mod_path, content).map_err(|e| format!("Failed to update mod.rs: {}", e))?;
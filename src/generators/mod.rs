@@ -3,4 +3,11 @@ pub mod docker;
 pub mod frontend;
 pub mod migration;
 pub mod entity;
+pub mod entity_builder;
+pub mod fields;
 pub mod resolver;
+pub mod scaffold;
+pub mod scalar;
+pub mod schema_file;
+pub mod seed;
+pub mod service;
@@ -1,4 +1,5 @@
 // src/generators/resolver.rs
+use crate::generators::entity::{module_name, table_name};
 use colored::*;
 use convert_case::{Case, Casing};
 use std::fs;
@@ -11,7 +12,7 @@ pub fn execute(name: &str) -> Result<(), String> {
         return Err("Not in a Nebulis project directory".into());
     }
 
-    let resolver_path = format!("backend/src/graphql/resolvers/{}.rs", name.to_lowercase());
+    let resolver_path = format!("backend/src/graphql/resolvers/{}.rs", module_name(name));
 
     // Créer les répertoires nécessaires
     fs::create_dir_all("backend/src/graphql/resolvers")
@@ -27,38 +28,54 @@ pub fn execute(name: &str) -> Result<(), String> {
 
     println!("{} Generated files:", "✓".green());
     println!("  - {}", resolver_path);
+    println!(
+        "{} Registered {pascal}Query and {pascal}Mutation in {}",
+        "✓".green(),
+        ROOT_PATH,
+        pascal = name.to_case(Case::Pascal)
+    );
     Ok(())
 }
 
 fn generate_resolver_content(name: &str) -> Result<String, String> {
     let pascal_name = name.to_case(Case::Pascal);
-    let snake_name = name.to_lowercase();
+    let snake_name = module_name(name);
+    let table = table_name(name);
 
     Ok(format!(
         r#"use async_graphql::{{Context, Object, Result, ID}};
-use crate::entities::{snake_name}::{pascal_name};
+use crate::entities::{snake_name}::{{
+    {pascal_name}, {pascal_name}Filter, {pascal_name}Input, {pascal_name}Pagination, {pascal_name}Sort,
+}};
 use crate::services::{snake_name}::{pascal_name}Service;
 
 #[derive(Default)]
-pub struct {pascal_name};
+pub struct {pascal_name}Query;
 
 #[Object]
-impl {pascal_name} {{
+impl {pascal_name}Query {{
     async fn get_{snake_name}(&self, ctx: &Context<'_>, id: ID) -> Result<Option<{pascal_name}>> {{
         let service = {pascal_name}Service::new(ctx.data()?);
         service.find_by_id(&id).await
     }}
 
-    async fn list_{snake_name}s(
+    async fn list_{table}(
         &self,
         ctx: &Context<'_>,
-        limit: Option<i32>,
-        offset: Option<i32>
+        filter: Option<{pascal_name}Filter>,
+        sort: Option<{pascal_name}Sort>,
+        pagination: Option<{pascal_name}Pagination>
     ) -> Result<Vec<{pascal_name}>> {{
         let service = {pascal_name}Service::new(ctx.data()?);
-        service.find_all(limit, offset).await
+        service.find_all(filter, sort, pagination).await
     }}
+}}
+
+#[derive(Default)]
+pub struct {pascal_name}Mutation;
 
+#[Object]
+impl {pascal_name}Mutation {{
     async fn create_{snake_name}(
         &self,
         ctx: &Context<'_>,
@@ -89,7 +106,8 @@ impl {pascal_name} {{
 }}
 "#,
         pascal_name = pascal_name,
-        snake_name = snake_name
+        snake_name = snake_name,
+        table = table
     ))
 }
 
@@ -97,7 +115,7 @@ fn update_resolvers_mod(name: &str) -> Result<(), String> {
     let mod_path = "backend/src/graphql/resolvers/mod.rs";
     let mut content = fs::read_to_string(mod_path).unwrap_or_else(|_| String::new());
 
-    let module_name = name.to_lowercase();
+    let module_name = module_name(name);
     if !content.contains(&format!("pub mod {};", module_name)) {
         if !content.is_empty() {
             content.push('\n');
@@ -106,7 +124,103 @@ fn update_resolvers_mod(name: &str) -> Result<(), String> {
         content.push_str(&format!("pub use {}::*;\n", module_name));
     }
 
-    fs::write(mod_path, content).map_err(|e| format!("Failed to update mod.rs: {}", e))?;
+    fs::write(mod_path, &content).map_err(|e| format!("Failed to update mod.rs: {}", e))?;
 
-    Ok(())
+    update_root(&registered_resolvers(&content))
+}
+
+/// Modules déclarés dans `graphql/resolvers/mod.rs`, dans leur ordre d'ajout
+fn registered_resolvers(mod_content: &str) -> Vec<String> {
+    mod_content
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("pub mod "))
+        .filter_map(|line| line.strip_suffix(';'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Régénère `graphql/root.rs` et déclare `resolvers` et `root` dans `graphql/mod.rs`
+fn update_root(resolvers: &[String]) -> Result<(), String> {
+    fs::write(ROOT_PATH, root_content(resolvers))
+        .map_err(|e| format!("Failed to write {}: {}", ROOT_PATH, e))?;
+
+    let mod_path = "backend/src/graphql/mod.rs";
+    let mut content = fs::read_to_string(mod_path).unwrap_or_else(|_| String::new());
+    for line in GRAPHQL_MOD_LINES {
+        if !content.lines().any(|existing| existing.trim() == line) {
+            if !content.is_empty() && !content.ends_with('\n') {
+                content.push('\n');
+            }
+            content.push_str(line);
+            content.push('\n');
+        }
+    }
+    fs::write(mod_path, content).map_err(|e| format!("Failed to update graphql/mod.rs: {}", e))
+}
+
+const ROOT_PATH: &str = "backend/src/graphql/root.rs";
+
+/// Déclarations de `graphql/mod.rs` nécessaires à `graphql::QueryRoot` et `graphql::MutationRoot`
+pub const GRAPHQL_MOD_LINES: [&str; 3] = [
+    "pub mod resolvers;",
+    "pub mod root;",
+    "pub use root::{MutationRoot, QueryRoot};",
+];
+
+/// Contenu de `graphql/root.rs` : `QueryRoot` et `MutationRoot` fusionnent les `<Name>Query`
+/// et `<Name>Mutation` de chaque resolver enregistré
+pub fn root_content(resolvers: &[String]) -> String {
+    let names = resolvers
+        .iter()
+        .map(|module| module.to_case(Case::Pascal))
+        .collect::<Vec<_>>();
+
+    let queries = std::iter::once("HealthQuery".to_string())
+        .chain(names.iter().map(|name| format!("{}Query", name)))
+        .collect::<Vec<_>>();
+    let mutation_root = if names.is_empty() {
+        "pub type MutationRoot = EmptyMutation;".to_string()
+    } else {
+        let mutations = names
+            .iter()
+            .map(|name| format!("{}Mutation", name))
+            .collect::<Vec<_>>();
+        format!(
+            "#[derive(MergedObject, Default)]\npub struct MutationRoot({});",
+            mutations.join(", ")
+        )
+    };
+    let (imports, resolvers_import) = if names.is_empty() {
+        ("EmptyMutation, MergedObject, Object", "")
+    } else {
+        (
+            "MergedObject, Object",
+            "use crate::graphql::resolvers::*;\n",
+        )
+    };
+
+    format!(
+        r#"// Generated by Nebulis CLI, regenerated by `nebulis generate resolver`
+use async_graphql::{{{imports}}};
+{resolvers_import}
+#[derive(Default)]
+pub struct HealthQuery;
+
+#[Object]
+impl HealthQuery {{
+    async fn health(&self) -> bool {{
+        true
+    }}
+}}
+
+#[derive(MergedObject, Default)]
+pub struct QueryRoot({queries});
+
+{mutation_root}
+"#,
+        imports = imports,
+        resolvers_import = resolvers_import,
+        queries = queries.join(", "),
+        mutation_root = mutation_root,
+    )
 }
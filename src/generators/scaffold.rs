@@ -0,0 +1,84 @@
+// src/generators/scaffold.rs
+use crate::generators::entity::{self, entity_path, module_name, table_name, EntityInfo};
+use crate::generators::migration::{self, created_table, entity_candidates, MigrationOptions};
+use crate::generators::{frontend, resolver};
+use colored::*;
+use convert_case::{Case, Casing};
+use std::path::Path;
+
+/// Génère en une commande l'entité, son repository, son service, sa migration, son resolver
+/// et, avec `--routes`, ses routes Remix
+pub fn execute(name: &str, fields: &[String], routes: bool) -> Result<(), String> {
+    println!("{} {}", "Generating scaffold:".blue(), name);
+
+    if !Path::new("backend").exists() {
+        return Err("Not in a Nebulis project directory".into());
+    }
+    check_names(name)?;
+
+    let path = entity_path(name);
+    if Path::new(&path).exists() {
+        return Err(format!("Entity already exists: {}", path));
+    }
+    if routes && !Path::new("frontend/app").exists() {
+        return Err("No Remix frontend found (frontend/app)".into());
+    }
+
+    entity::execute(name, fields, false)?;
+    migration::execute(
+        &format!("create_{}", table_name(name)),
+        &MigrationOptions::default(),
+    )?;
+    resolver::execute(name)?;
+
+    if routes {
+        let info = EntityInfo::load(name)?;
+        let paths = frontend::generate_routes(&info)?;
+        println!("{} Generated routes:", "✓".green());
+        for path in paths {
+            println!("  - {}", path);
+        }
+    }
+
+    println!(
+        "\n{} {} scaffolded (table {})",
+        "✓".green(),
+        name.to_case(Case::Pascal),
+        table_name(name)
+    );
+    Ok(())
+}
+
+/// Vérifie que chaque générateur dérive les mêmes noms de l'entité, pour que la migration
+/// `create_<table>` retrouve le fichier de l'entité et que le resolver importe le bon service
+pub fn check_names(name: &str) -> Result<(), String> {
+    let pascal = name.to_case(Case::Pascal);
+    if pascal.is_empty() || !pascal.chars().next().unwrap().is_ascii_alphabetic() {
+        return Err(format!("Invalid entity name: {}", name));
+    }
+    if !pascal.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!("Invalid entity name: {}", name));
+    }
+
+    let table = table_name(name);
+    if table_name(&pascal) != table || module_name(&pascal) != module_name(name) {
+        return Err(format!(
+            "Entity name {} does not round-trip through {} (table {})",
+            name, pascal, table
+        ));
+    }
+    if created_table(&format!("create_{}", table)).as_deref() != Some(table.as_str()) {
+        return Err(format!(
+            "Migration name create_{} does not parse back",
+            table
+        ));
+    }
+    if !entity_candidates(&table).contains(&entity_path(name)) {
+        return Err(format!(
+            "Migration create_{} would not find the entity file {}",
+            table,
+            entity_path(name)
+        ));
+    }
+    Ok(())
+}
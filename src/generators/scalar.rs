@@ -0,0 +1,376 @@
+// src/generators/scalar.rs
+use crate::generators::entity::module_name;
+use std::fs;
+use std::path::Path;
+
+/// Types sémantiques de `VALID_TYPES`, générés comme scalaires GraphQL dans `graphql/scalars`
+pub const SCALARS: [&str; 7] = ["Email", "Phone", "Url", "Slug", "Money", "Uuid", "Password"];
+
+/// Scalaires référencés par un type de champ (`Option<Email>`, `Vec<Url>`...)
+pub fn scalars_in(type_: &str) -> Vec<&'static str> {
+    type_
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter_map(|part| SCALARS.iter().find(|scalar| **scalar == part).copied())
+        .collect()
+}
+
+/// Champ `Password` : haché à l'écriture, il n'est ni exposé en sortie, ni filtrable, ni triable
+pub fn is_password(type_: &str) -> bool {
+    scalars_in(type_).contains(&"Password")
+}
+
+/// Écrit `graphql/scalars/<scalaire>.rs` pour chaque scalaire qui n'existe pas encore, les
+/// déclare dans `graphql/scalars/mod.rs` et déclare `scalars` dans `graphql/mod.rs`
+pub fn ensure_scalars(backend_src: &str, scalars: &[&str]) -> Result<Vec<String>, String> {
+    let scalars_dir = format!("{}/graphql/scalars", backend_src);
+    fs::create_dir_all(&scalars_dir)
+        .map_err(|e| format!("Failed to create scalars directory: {}", e))?;
+
+    let mut paths = Vec::new();
+    for scalar in scalars {
+        let content = scalar_content(scalar)?;
+        let path = format!("{}/{}.rs", scalars_dir, module_name(scalar));
+        if Path::new(&path).exists() {
+            continue;
+        }
+        fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+        paths.push(path);
+
+        // Le hachage des mots de passe est la seule dépendance propre à un scalaire
+        if *scalar == "Password" {
+            ensure_dependency(backend_src, "argon2", "\"0.5\"")?;
+        }
+    }
+
+    let mut lines = scalars
+        .iter()
+        .flat_map(|scalar| {
+            let module = module_name(scalar);
+            [
+                format!("pub mod {};", module),
+                format!("pub use {}::{};", module, scalar),
+            ]
+        })
+        .collect::<Vec<_>>();
+    lines.insert(0, "// Generated by Nebulis CLI".to_string());
+    ensure_lines(&format!("{}/mod.rs", scalars_dir), &lines)?;
+    ensure_lines(
+        &format!("{}/graphql/mod.rs", backend_src),
+        &["pub mod scalars;".to_string()],
+    )?;
+
+    Ok(paths)
+}
+
+/// Ajoute à un fichier les lignes qu'il ne contient pas encore
+fn ensure_lines(path: &str, lines: &[String]) -> Result<(), String> {
+    let mut content = fs::read_to_string(path).unwrap_or_default();
+    let missing = lines
+        .iter()
+        .filter(|line| {
+            !content
+                .lines()
+                .any(|existing| existing.trim() == line.as_str())
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    for line in missing {
+        content.push_str(&line);
+        content.push('\n');
+    }
+    fs::write(path, content).map_err(|e| format!("Failed to update {}: {}", path, e))
+}
+
+/// Ajoute une dépendance à la section `[dependencies]` du `Cargo.toml` du backend
+fn ensure_dependency(backend_src: &str, name: &str, version: &str) -> Result<(), String> {
+    let cargo_path = Path::new(backend_src)
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join("Cargo.toml");
+    let content = fs::read_to_string(&cargo_path)
+        .map_err(|e| format!("Could not read {}: {}", cargo_path.display(), e))?;
+    if content
+        .lines()
+        .any(|line| line.split('=').next().map(str::trim) == Some(name))
+    {
+        return Ok(());
+    }
+
+    let dependency = format!("{} = {}", name, version);
+    let content = match content.find("[dependencies]\n") {
+        Some(start) => {
+            let end = start + "[dependencies]\n".len();
+            format!("{}{}\n{}", &content[..end], dependency, &content[end..])
+        }
+        None => format!("{}\n[dependencies]\n{}\n", content.trim_end(), dependency),
+    };
+    fs::write(&cargo_path, content)
+        .map_err(|e| format!("Failed to update {}: {}", cargo_path.display(), e))
+}
+
+/// Contenu de `graphql/scalars/<scalaire>.rs`
+fn scalar_content(scalar: &str) -> Result<String, String> {
+    let string_scalar = |doc: &str, from_str: &str| {
+        STRING_SCALAR_TEMPLATE
+            .replace("{doc}", doc)
+            .replace("{from_str}", from_str)
+            .replace("{name}", scalar)
+    };
+
+    match scalar {
+        "Email" => Ok(string_scalar(
+            "Adresse email, normalisée en minuscules",
+            r#"let value = value.trim().to_lowercase();
+        if validator::validate_email(value.as_str()) {
+            Ok(Self(value))
+        } else {
+            Err(format!("Invalid email address: {}", value))
+        }"#,
+        )),
+        "Phone" => Ok(string_scalar(
+            "Numéro de téléphone, stocké sans séparateurs (`+33 6 12-34-56-78` -> `+33612345678`)",
+            r#"let digits = value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '.' | '-' | '(' | ')'))
+            .collect::<String>();
+        let number = digits.strip_prefix('+').unwrap_or(&digits);
+        if (6..=15).contains(&number.len()) && number.chars().all(|c| c.is_ascii_digit()) {
+            Ok(Self(digits))
+        } else {
+            Err(format!("Invalid phone number: {}", value))
+        }"#,
+        )),
+        "Url" => Ok(string_scalar(
+            "URL absolue",
+            r#"let value = value.trim();
+        if validator::validate_url(value) {
+            Ok(Self(value.to_string()))
+        } else {
+            Err(format!("Invalid URL: {}", value))
+        }"#,
+        )),
+        "Slug" => Ok(string_scalar(
+            "Identifiant d'URL : minuscules, chiffres et tirets simples (`mon-article-2`)",
+            r#"let valid_char = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+        let valid = !value.is_empty()
+            && value.split('-').all(|part| !part.is_empty() && part.chars().all(valid_char));
+        if valid {
+            Ok(Self(value.to_string()))
+        } else {
+            Err(format!("Invalid slug: {}", value))
+        }"#,
+        )),
+        "Money" => Ok(MONEY_TEMPLATE.to_string()),
+        "Uuid" => Ok(UUID_TEMPLATE.to_string()),
+        "Password" => Ok(PASSWORD_TEMPLATE.to_string()),
+        _ => Err(format!("Unknown scalar: {}", scalar)),
+    }
+}
+
+/// Scalaire chaîne validé par `FromStr`, stocké en `string`
+const STRING_SCALAR_TEMPLATE: &str = r#"// Generated by Nebulis CLI
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// {doc}
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct {name}(String);
+
+impl {name} {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for {name} {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        {from_str}
+    }
+}
+
+#[Scalar]
+impl ScalarType for {name} {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(value) => Ok(value.parse()?),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.clone())
+    }
+}
+"#;
+
+/// Montant décimal, stocké en `decimal` et exposé en chaîne pour ne perdre aucune précision
+const MONEY_TEMPLATE: &str = r#"// Generated by Nebulis CLI
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use surrealdb::sql::Number;
+
+/// Montant décimal (`"19.99"`), stocké en `decimal` par SurrealDB
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Money(Number);
+
+impl Money {
+    pub fn amount(&self) -> Number {
+        self.0.clone()
+    }
+}
+
+impl FromStr for Money {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let number = Number::try_from(value.trim())
+            .map_err(|_| format!("Invalid amount: {}", value))?;
+        if number.is_nan() {
+            return Err(format!("Invalid amount: {}", value));
+        }
+        Ok(Self(Number::Decimal(number.to_decimal())))
+    }
+}
+
+#[Scalar]
+impl ScalarType for Money {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(value) => Ok(value.parse()?),
+            Value::Number(number) => Ok(number.to_string().parse()?),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.to_decimal().to_string())
+    }
+}
+"#;
+
+/// UUID, stocké en `uuid` par SurrealDB
+const UUID_TEMPLATE: &str = r#"// Generated by Nebulis CLI
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// UUID, stocké en `uuid` par SurrealDB
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Uuid(surrealdb::sql::Uuid);
+
+impl Uuid {
+    pub fn new_v4() -> Self {
+        Self(surrealdb::sql::Uuid::new_v4())
+    }
+}
+
+impl FromStr for Uuid {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        surrealdb::sql::Uuid::try_from(value.trim())
+            .map(Self)
+            .map_err(|_| format!("Invalid UUID: {}", value))
+    }
+}
+
+#[Scalar]
+impl ScalarType for Uuid {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(value) => Ok(value.parse()?),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.to_raw())
+    }
+}
+"#;
+
+/// Mot de passe haché avec Argon2 dès la lecture de l'argument GraphQL ; seul le hash est
+/// stocké, et il n'est jamais renvoyé par l'API
+const PASSWORD_TEMPLATE: &str = r#"// Generated by Nebulis CLI
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Longueur minimale d'un mot de passe en clair
+const MIN_LENGTH: usize = 8;
+
+/// Hash Argon2 (format PHC) d'un mot de passe, compatible avec `crypto::argon2::compare`
+///
+/// `Serialize` sert à écrire le hash en base depuis l'Input ; les champs `Password` des entités
+/// portent `#[serde(skip_serializing)]` pour que le hash ne sorte ni dans les logs ni en JSON.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Password(String);
+
+impl Password {
+    /// Vérifie un mot de passe en clair contre le hash
+    pub fn verify(&self, plain: &str) -> bool {
+        PasswordHash::new(&self.0)
+            .map(|hash| Argon2::default().verify_password(plain.as_bytes(), &hash).is_ok())
+            .unwrap_or(false)
+    }
+}
+
+/// Hache un mot de passe en clair
+impl FromStr for Password {
+    type Err = String;
+
+    fn from_str(plain: &str) -> Result<Self, Self::Err> {
+        if plain.chars().count() < MIN_LENGTH {
+            return Err(format!(
+                "Password must be at least {} characters long",
+                MIN_LENGTH
+            ));
+        }
+        let salt = SaltString::generate(&mut OsRng);
+        Argon2::default()
+            .hash_password(plain.as_bytes(), &salt)
+            .map(|hash| Self(hash.to_string()))
+            .map_err(|e| format!("Failed to hash password: {}", e))
+    }
+}
+
+impl fmt::Debug for Password {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Password(***)")
+    }
+}
+
+#[Scalar]
+impl ScalarType for Password {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(plain) => Ok(plain.parse()?),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    /// Les champs `Password` sont exclus des types de sortie ; le hash n'est jamais exposé
+    fn to_value(&self) -> Value {
+        Value::Null
+    }
+}
+"#;
@@ -0,0 +1,285 @@
+// src/generators/schema_file.rs
+use crate::db::schema::Schema;
+use crate::generators::entity::{
+    self, find_object, parse_enum, parse_validations, EntityInfo, Field, FieldValidation,
+    ObjectType, RelationType,
+};
+use crate::generators::fields;
+use crate::generators::migration;
+use crate::generators::resolver;
+use colored::*;
+use convert_case::{Case, Casing};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+/// Fichier de schéma du projet, source unique des entités
+pub const SCHEMA_PATH: &str = "nebulis.schema.toml";
+
+/// Contenu de `nebulis.schema.toml` : une table `[[entity]]` par entité
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SchemaFile {
+    #[serde(default, rename = "entity")]
+    pub entities: Vec<EntityDef>,
+}
+
+/// Entité déclarée par `[[entity]]`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EntityDef {
+    pub name: String,
+    #[serde(default, rename = "field")]
+    pub fields: Vec<FieldDef>,
+    #[serde(default, rename = "relation")]
+    pub relations: Vec<RelationDef>,
+}
+
+/// Champ déclaré par `[[entity.field]]` : un type, un enum (`enum = [...]`) ou un objet
+/// imbriqué (`type = "Address"` suivi de ses `[[entity.field.field]]`) ; `optional` et
+/// `default` correspondent à `name?:type=default` dans `--fields`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    #[serde(rename = "enum")]
+    pub variants: Option<Vec<String>>,
+    #[serde(default)]
+    pub optional: bool,
+    pub default: Option<toml::Value>,
+    #[serde(default)]
+    pub validate: Vec<String>,
+    #[serde(rename = "field")]
+    pub fields: Option<Vec<FieldDef>>,
+}
+
+/// Relation déclarée par `[[entity.relation]]`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RelationDef {
+    /// `hasOne`, `hasMany`, `belongsTo` ou `manyToMany`
+    pub kind: String,
+    pub target: String,
+    /// Table d'arêtes d'une relation `manyToMany`
+    pub via: Option<String>,
+}
+
+impl SchemaFile {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        Self::parse(&content).map_err(|e| format!("Invalid {}: {}", path.display(), e))
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+
+    /// Entités du schéma, validées comme le serait `--fields`
+    pub fn entity_infos(&self) -> Result<Vec<EntityInfo>, String> {
+        let mut infos: Vec<EntityInfo> = Vec::new();
+        for entity in &self.entities {
+            let info = entity.to_entity_info()?;
+            if infos.iter().any(|other| other.name == info.name) {
+                return Err(format!("Entity {} is declared twice", info.name));
+            }
+            infos.push(info);
+        }
+        Ok(infos)
+    }
+}
+
+impl EntityDef {
+    pub fn to_entity_info(&self) -> Result<EntityInfo, String> {
+        let mut info = EntityInfo {
+            name: self.name.to_case(Case::Pascal),
+            ..Default::default()
+        };
+        if info.name.is_empty() {
+            return Err("Entity without a name".into());
+        }
+
+        for field in &self.fields {
+            let field = field_from_def(field, &mut info)
+                .map_err(|e| format!("Entity {}: {}", info.name, e))?;
+            info.fields.push(field);
+        }
+        for relation in &self.relations {
+            let relation = relation
+                .to_relation()
+                .map_err(|e| format!("Entity {}: {}", info.name, e))?;
+            info.relations.push(relation);
+        }
+        Ok(info)
+    }
+}
+
+impl RelationDef {
+    fn to_relation(&self) -> Result<RelationType, String> {
+        let target = self.target.to_case(Case::Pascal);
+        match (self.kind.as_str(), &self.via) {
+            ("hasOne", None) => Ok(RelationType::HasOne(target)),
+            ("hasMany", None) => Ok(RelationType::HasMany(target)),
+            ("belongsTo", None) => Ok(RelationType::BelongsTo(target)),
+            ("manyToMany", Some(edge)) => {
+                Ok(RelationType::ManyToMany(target, edge.to_case(Case::Snake)))
+            }
+            ("manyToMany", None) => Err(format!(
+                "Missing edge table for manyToMany relation to {} (via = \"edge\")",
+                target
+            )),
+            ("hasOne" | "hasMany" | "belongsTo", Some(_)) => Err(format!(
+                "Only manyToMany relations take an edge table (relation to {})",
+                target
+            )),
+            (kind, _) => Err(format!("Invalid relation type: {}", kind)),
+        }
+    }
+}
+
+/// Convertit un champ du schéma, en déclarant ses enums et objets imbriqués dans `info`
+fn field_from_def(field: &FieldDef, info: &mut EntityInfo) -> Result<Field, String> {
+    let name = field.name.trim().to_string();
+    if name.is_empty() {
+        return Err("Field without a name".into());
+    }
+
+    let field_type = match (&field.type_, &field.variants, &field.fields) {
+        (None, Some(variants), None) => parse_enum(&name, &variants.join(","), info)?,
+        (Some(object_name), None, Some(object_fields)) => {
+            let object_name = object_name.trim().to_case(Case::Pascal);
+            let mut object = ObjectType {
+                name: object_name.clone(),
+                fields: Vec::new(),
+            };
+            for object_field in object_fields {
+                object.fields.push(field_from_def(object_field, info)?);
+            }
+            if object.fields.is_empty() {
+                return Err(format!(
+                    "Object {} must declare at least one field",
+                    object_name
+                ));
+            }
+            if find_object(&info.objects, &object_name).is_some() {
+                return Err(format!("Object {} is declared twice", object_name));
+            }
+            info.objects.push(object);
+            object_name
+        }
+        (Some(field_type), None, None) => {
+            // Un objet ou un enum déjà déclaré peut être réutilisé par son nom
+            let field_type = field_type.trim().to_string();
+            fields::check_type(&field_type, info)?;
+            field_type
+        }
+        (None, None, _) => return Err(format!("Missing type for field: {}", name)),
+        _ => {
+            return Err(format!(
+                "Field {} must declare exactly one of type, enum or type with nested fields",
+                name
+            ))
+        }
+    };
+
+    let mut validation = FieldValidation::default();
+    if let Some(default) = &field.default {
+        let default = match default {
+            toml::Value::String(value) => value.clone(),
+            value => value.to_string(),
+        };
+        fields::check_default(&field_type, &default, info)
+            .map_err(|e| format!("Field {}: {}", name, e))?;
+        validation.default = Some(default);
+    }
+    let field_type = if field.optional {
+        fields::check_optional(&name, &field_type)?;
+        format!("Option<{}>", field_type)
+    } else {
+        field_type
+    };
+
+    let rules = field
+        .validate
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    parse_validations(&mut validation, &rules).map_err(|e| format!("Field {}: {}", name, e))?;
+
+    Ok((name, field_type, validation))
+}
+
+/// `nebulis generate --from-schema` : régénère les entités, leurs couches, leurs resolvers et
+/// leurs types frontend, puis la migration qui aligne la base sur le schéma
+pub fn execute(path: &str) -> Result<(), String> {
+    println!("{} {}", "Generating from schema:".blue(), path);
+
+    if !Path::new("backend").exists() {
+        return Err("Not in a Nebulis project directory".into());
+    }
+
+    let schema = SchemaFile::load(Path::new(path))?;
+    let infos = schema.entity_infos()?;
+    if infos.is_empty() {
+        return Err(format!("No [[entity]] declared in {}", path));
+    }
+
+    for info in &infos {
+        println!();
+        entity::generate(info)?;
+        resolver::execute(&info.name)?;
+    }
+
+    // La migration part des entités du schéma, pas des fichiers Rust régénérés
+    println!();
+    migration::execute_to_schema("sync_schema", &Schema::from_infos(&infos), false)?;
+
+    println!(
+        "\n{} {} entities generated from {}",
+        "✓".green(),
+        infos.len(),
+        path
+    );
+    Ok(())
+}
+
+/// Exemple écrit à la création d'un projet
+pub const SCHEMA_TEMPLATE: &str = r#"# Entities of the project, generated with `nebulis generate --from-schema`
+#
+# [[entity]]
+# name = "Article"
+#
+# [[entity.field]]
+# name = "title"
+# type = "String"
+# validate = ["required", "min_length=3"]
+#
+# [[entity.field]]
+# name = "views"
+# type = "i32"
+# default = 0
+#
+# [[entity.field]]
+# name = "bio"
+# type = "String"
+# optional = true
+#
+# [[entity.field]]
+# name = "status"
+# enum = ["draft", "published"]
+#
+# [[entity.field]]
+# name = "address"
+# type = "Address"
+#
+# [[entity.field.field]]
+# name = "street"
+# type = "String"
+#
+# [[entity.relation]]
+# kind = "manyToMany"
+# target = "Tag"
+# via = "tagged"
+"#;
@@ -0,0 +1,380 @@
+// src/generators/seed.rs
+use crate::db::{self, seeder, DbConfig};
+use crate::generators::entity::{
+    find_enum, find_object, module_name, table_name, EntityInfo, FieldValidation, RelationType,
+};
+use colored::*;
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+
+/// Les données factices ne sont exécutées que par `nebulis db seed --env development`
+const FAKE_SEEDS_DIR: &str = "database/seeds/development";
+
+const FIRST_NAMES: [&str; 12] = [
+    "Alice",
+    "Bruno",
+    "Chloé",
+    "David",
+    "Emma",
+    "Farid",
+    "Gabrielle",
+    "Hugo",
+    "Inès",
+    "Jules",
+    "Karim",
+    "Léa",
+];
+const LAST_NAMES: [&str; 10] = [
+    "Martin", "Bernard", "Dubois", "Thomas", "Robert", "Richard", "Petit", "Durand", "Leroy",
+    "Moreau",
+];
+const WORDS: [&str; 16] = [
+    "nebula", "orbit", "comet", "stellar", "galaxy", "quasar", "lunar", "solar", "cosmic",
+    "meteor", "pulsar", "zenith", "aurora", "eclipse", "horizon", "vertex",
+];
+
+/// Générateur pseudo-aléatoire déterministe (xorshift), pour des seeds reproductibles
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: &str) -> Self {
+        let hash = seed.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+        Self(hash | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Entier dans `[min, max]`
+    fn range(&mut self, min: i64, max: i64) -> i64 {
+        if max <= min {
+            return min;
+        }
+        min + (self.next() % (max - min + 1) as u64) as i64
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.next() as usize % items.len()]
+    }
+}
+
+/// Point d'entrée de `nebulis generate seed <Entity> --count N`
+pub fn execute(name: &str, count: usize) -> Result<(), String> {
+    println!("{} {}", "Generating seed:".blue(), name);
+
+    if !Path::new("backend").exists() {
+        return Err("Not in a Nebulis project directory".into());
+    }
+
+    let info = EntityInfo::load(name)?;
+
+    // Les relations belongsTo pointent vers des enregistrements déjà seedés
+    let mut related_ids = Vec::new();
+    for relation in &info.relations {
+        if let RelationType::BelongsTo(target) = relation {
+            related_ids.push((target.clone(), seeded_ids(target)?));
+        }
+    }
+
+    for (field_name, _, validation) in &info.fields {
+        if validation.pattern.is_some() {
+            println!(
+                "{} {} has a pattern validation, generated values may not match it",
+                "Warning:".yellow(),
+                field_name
+            );
+        }
+    }
+
+    let content = generate_seed_content(name, &info, count, &related_ids)?;
+    let seed_path = format!("{}/{}.surql", FAKE_SEEDS_DIR, module_name(name));
+    fs::create_dir_all(FAKE_SEEDS_DIR)
+        .map_err(|e| format!("Failed to create seeds directory: {}", e))?;
+    fs::write(&seed_path, content).map_err(|e| format!("Failed to write seed file: {}", e))?;
+
+    println!("{} Generated files:", "✓".green());
+    println!("  - {} ({} records)", seed_path, count);
+    Ok(())
+}
+
+pub fn run_seeds(env: &str) -> Result<(), String> {
+    let root = Path::new(".");
+    let config = DbConfig::load(root)?;
+    seeder::check_env(&config, env)?;
+
+    let seeded = db::block_on(async {
+        let db = db::connect(&config).await?;
+        seeder::run(&db, root, env).await
+    })??;
+
+    if seeded.is_empty() {
+        println!("  No seed files found");
+    }
+
+    Ok(())
+}
+
+/// Identifiants des enregistrements créés par `database/seeds/development/<target>.surql`
+fn seeded_ids(target: &str) -> Result<Vec<String>, String> {
+    let seed_path = format!("{}/{}.surql", FAKE_SEEDS_DIR, module_name(target));
+    let content = fs::read_to_string(&seed_path).map_err(|_| {
+        format!("No seed found for {target}: run `nebulis generate seed {target}` first")
+    })?;
+
+    let table = table_name(target);
+    let id_regex = Regex::new(&format!(
+        r"(?:CREATE|UPSERT)\s+({}:\w+)",
+        regex::escape(&table)
+    ))
+    .unwrap();
+    let ids = id_regex
+        .captures_iter(&content)
+        .map(|cap| cap[1].to_string())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        return Err(format!(
+            "{} does not create any {} record",
+            seed_path, table
+        ));
+    }
+    Ok(ids)
+}
+
+/// Génère les instructions d'un fichier de seed ; chaque enregistrement a un identifiant fixe
+/// et est écrit avec `UPSERT ... MERGE`, si bien que `nebulis db seed` peut être relancé
+pub fn generate_seed_content(
+    name: &str,
+    info: &EntityInfo,
+    count: usize,
+    related_ids: &[(String, Vec<String>)],
+) -> Result<String, String> {
+    let table = table_name(name);
+    let mut rng = Rng::new(&table);
+    let mut records = vec![format!(
+        "// Generated by Nebulis CLI: {} {} records",
+        count, name
+    )];
+
+    for index in 1..=count {
+        let mut values = Vec::new();
+        for (field_name, type_, validation) in &info.fields {
+            let value = fake_value(&mut rng, &table, field_name, type_, validation, index, info)
+                .map_err(|e| format!("Field {}: {}", field_name, e))?;
+            values.push(format!("    {}: {}", field_name, value));
+        }
+
+        for (target, ids) in related_ids {
+            let id = &ids[rng.next() as usize % ids.len()];
+            values.push(format!("    {}_id: {}", module_name(target), id));
+        }
+
+        records.push(format!(
+            "UPSERT {}:{} MERGE {{\n{}\n}};",
+            table,
+            index,
+            values.join(",\n")
+        ));
+    }
+
+    Ok(records.join("\n\n") + "\n")
+}
+
+/// Valeur SurrealQL plausible respectant les validations du champ ; un champ `unique` reçoit
+/// une valeur dérivée de `index`, pour ne pas enfreindre l'index UNIQUE de la migration
+fn fake_value(
+    rng: &mut Rng,
+    table: &str,
+    field_name: &str,
+    type_: &str,
+    validation: &FieldValidation,
+    index: usize,
+    info: &EntityInfo,
+) -> Result<String, String> {
+    // Un champ optionnel est parfois laissé vide
+    if let Some(inner) = type_
+        .strip_prefix("Option<")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        if !validation.required && !validation.unique && index.is_multiple_of(3) {
+            return Ok("NONE".to_string());
+        }
+        return fake_value(rng, table, field_name, inner, validation, index, info);
+    }
+
+    if let Some(enum_type) = find_enum(&info.enums, type_) {
+        let values = enum_type.values();
+        return Ok(quote(&values[rng.next() as usize % values.len()]));
+    }
+
+    // Un objet imbriqué reçoit une valeur pour chacun de ses champs
+    if let Some(object) = find_object(&info.objects, type_) {
+        let mut values = Vec::new();
+        for (name, type_, validation) in &object.fields {
+            let value = fake_value(rng, table, name, type_, validation, index, info)?;
+            values.push(format!("{}: {}", name, value));
+        }
+        return Ok(format!("{{ {} }}", values.join(", ")));
+    }
+
+    if validation.email {
+        return Ok(quote(&fake_email(rng, index)));
+    }
+    if validation.url {
+        return Ok(quote(&format!("https://example.com/{}/{}", table, index)));
+    }
+
+    let value = match type_ {
+        "Email" => quote(&fake_email(rng, index)),
+        "Url" => quote(&format!("https://example.com/{}/{}", table, index)),
+        "Phone" if validation.unique => quote(&format!("+3361{:07}", index)),
+        "Phone" => quote(&format!("+3361{:07}", rng.range(0, 9_999_999))),
+        "Slug" => quote(&format!(
+            "{}-{}-{}",
+            rng.pick(&WORDS),
+            rng.pick(&WORDS),
+            index
+        )),
+        "Password" => "crypto::argon2::generate(\"password\")".to_string(),
+        "Uuid" => "rand::uuid::v4()".to_string(),
+        "Json" => format!("{{ seed: {} }}", index),
+        "Money" => {
+            let (min, max) = bounds(validation, 0.0, 1000.0);
+            let cents = number(rng, validation, index, min * 100.0, max * 100.0)?;
+            format!("{:.2}dec", cents as f64 / 100.0)
+        }
+        "i32" | "i64" | "u32" | "u64" | "usize" => {
+            let (min, max) = bounds(validation, 0.0, 1000.0);
+            number(rng, validation, index, min, max)?.to_string()
+        }
+        "f32" | "f64" => {
+            let (min, max) = bounds(validation, 0.0, 1000.0);
+            let hundredths = number(rng, validation, index, min * 100.0, max * 100.0)?;
+            format!("{:.2}", hundredths as f64 / 100.0)
+        }
+        "bool" => rng.next().is_multiple_of(2).to_string(),
+        "DateTime" | "DateTime<Utc>" => format!(
+            "d\"2024-{:02}-{:02}T{:02}:00:00Z\"",
+            rng.range(1, 12),
+            rng.range(1, 28),
+            rng.range(0, 23)
+        ),
+        "Vec<String>" => format!("[{}, {}]", quote(rng.pick(&WORDS)), quote(rng.pick(&WORDS))),
+        _ => quote(&fake_text(rng, field_name, validation, index)?),
+    };
+    Ok(value)
+}
+
+/// Entier de `[min, max]` : aléatoire, ou le `index`-ième de l'intervalle pour un champ `unique`
+fn number(
+    rng: &mut Rng,
+    validation: &FieldValidation,
+    index: usize,
+    min: f64,
+    max: f64,
+) -> Result<i64, String> {
+    let (min, max) = (min.ceil() as i64, max.floor() as i64);
+    if !validation.unique {
+        return Ok(rng.range(min, max));
+    }
+
+    let value = min + index as i64 - 1;
+    if value > max {
+        return Err(format!(
+            "unique values between {} and {} cannot cover {} records",
+            min, max, index
+        ));
+    }
+    Ok(value)
+}
+
+fn fake_email(rng: &mut Rng, index: usize) -> String {
+    format!(
+        "{}.{}{}@example.com",
+        rng.pick(&FIRST_NAMES).to_lowercase(),
+        rng.pick(&LAST_NAMES).to_lowercase(),
+        index
+    )
+    .replace(['é', 'è'], "e")
+}
+
+/// Texte adapté au nom du champ, ajusté à `minLength`/`maxLength`
+fn fake_text(
+    rng: &mut Rng,
+    field_name: &str,
+    validation: &FieldValidation,
+    index: usize,
+) -> Result<String, String> {
+    let min = validation.min_length.unwrap_or(0);
+    if validation.max_length.is_some_and(|max| max < min) {
+        return Err(format!(
+            "min_length {} is greater than max_length {}",
+            min,
+            validation.max_length.unwrap_or_default()
+        ));
+    }
+
+    let mut text = if field_name.contains("name") {
+        format!("{} {}", rng.pick(&FIRST_NAMES), rng.pick(&LAST_NAMES))
+    } else {
+        let words = rng.range(2, 5);
+        (0..words)
+            .map(|_| rng.pick(&WORDS))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let suffix = if validation.unique {
+        format!(" {}", index)
+    } else {
+        String::new()
+    };
+
+    while text.chars().count() + suffix.chars().count() < min {
+        text.push(' ');
+        text.push_str(rng.pick(&WORDS));
+    }
+
+    if let Some(max) = validation.max_length {
+        if suffix.chars().count() > max {
+            return Err(format!(
+                "max_length {} is too short for unique values up to {}",
+                max, index
+            ));
+        }
+        text = text.chars().take(max - suffix.chars().count()).collect();
+        // Un espace final n'est retiré que si `min_length` reste respecté
+        let trimmed = text.trim_end();
+        if trimmed.chars().count() + suffix.chars().count() >= min {
+            text = trimmed.to_string();
+        }
+    }
+
+    Ok(text + &suffix)
+}
+
+/// Bornes `min`/`max` d'un champ numérique
+fn bounds(validation: &FieldValidation, default_min: f64, default_max: f64) -> (f64, f64) {
+    let min = validation
+        .min
+        .as_deref()
+        .and_then(|v| v.parse::<f64>().ok())
+        .unwrap_or(default_min);
+    let max = validation
+        .max
+        .as_deref()
+        .and_then(|v| v.parse::<f64>().ok())
+        .unwrap_or(min.max(default_min) + default_max);
+    (min, max.max(min))
+}
+
+fn quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value))
+}
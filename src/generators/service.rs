@@ -0,0 +1,518 @@
+// src/generators/service.rs
+use crate::generators::entity::{
+    find_enum, find_object, module_name, table_name, EntityInfo, Field, RelationType,
+};
+use crate::generators::scalar;
+use colored::*;
+use convert_case::{Case, Casing};
+use std::fs;
+use std::path::Path;
+
+/// Condition d'un filtre : paramètre lié, valeur Rust (`Option<_>`) et clause SurrealQL
+struct FilterClause {
+    param: String,
+    value: String,
+    condition: String,
+}
+
+/// Point d'entrée de `nebulis generate service <Entity>`, à partir d'une entité existante
+pub fn execute(name: &str) -> Result<(), String> {
+    println!("{} {}", "Generating service:".blue(), name);
+
+    if !Path::new("backend").exists() {
+        return Err("Not in a Nebulis project directory".into());
+    }
+
+    let info = EntityInfo::load(name)?;
+    let paths = generate_layers(&info)?;
+
+    println!("{} Generated files:", "✓".green());
+    for path in paths {
+        println!("  - {}", path);
+    }
+    Ok(())
+}
+
+/// Écrit `repositories/<entity>.rs` et `services/<entity>.rs`, puis met à jour les `mod.rs`
+pub fn generate_layers(info: &EntityInfo) -> Result<Vec<String>, String> {
+    let module_name = module_name(&info.name);
+    let repository_path = format!("backend/src/repositories/{}.rs", module_name);
+    let service_path = format!("backend/src/services/{}.rs", module_name);
+
+    for (path, content) in [
+        (&repository_path, generate_repository_content(info)),
+        (&service_path, generate_service_content(info)),
+    ] {
+        let directory = Path::new(path).parent().unwrap_or(Path::new("."));
+        fs::create_dir_all(directory)
+            .map_err(|e| format!("Failed to create {}: {}", directory.display(), e))?;
+        fs::write(path, content).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    }
+
+    update_mod("backend/src/repositories/mod.rs", &module_name)?;
+    update_mod("backend/src/services/mod.rs", &module_name)?;
+
+    Ok(vec![repository_path, service_path])
+}
+
+/// Génère le repository : requêtes SurrealDB, soft delete, filtre, tri et pagination
+pub fn generate_repository_content(info: &EntityInfo) -> String {
+    let pascal_name = info.name.to_case(Case::Pascal);
+    let module_name = module_name(&info.name);
+    let table = table_name(&info.name);
+
+    let mut clauses = vec![
+        FilterClause {
+            param: "from_date".to_string(),
+            value: "filter.from_date".to_string(),
+            condition: "created_at >= <datetime> $from_date".to_string(),
+        },
+        FilterClause {
+            param: "to_date".to_string(),
+            value: "filter.to_date".to_string(),
+            condition: "created_at <= <datetime> $to_date".to_string(),
+        },
+    ];
+    clauses.extend(field_clauses(&info.fields, info, "", "filter"));
+    clauses.extend(relation_clauses(info));
+
+    let filters = clauses
+        .iter()
+        .map(|clause| {
+            format!(
+                "        let {param} = {value};\n        if {param}.is_some() {{\n            conditions.push(\"{condition}\");\n        }}",
+                param = clause.param,
+                value = clause.value,
+                condition = clause.condition
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let filter_bindings = clauses
+        .iter()
+        .map(|clause| format!("            .bind((\"{0}\", {0}))", clause.param))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let sort_fields = info
+        .fields
+        .iter()
+        .filter(|(_, type_, _)| {
+            find_object(&info.objects, type_).is_none() && !scalar::is_password(type_)
+        })
+        .map(|(name, _, _)| {
+            format!(
+                "                    {}SortField::{} => \"{}\",",
+                pascal_name,
+                name.to_case(Case::Pascal),
+                name
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let foreign_keys = foreign_keys(info);
+    let assignments = info
+        .fields
+        .iter()
+        .map(|(name, _, _)| format!("{0} = ${0}", name))
+        .chain(
+            foreign_keys
+                .iter()
+                .map(|(key, target)| format!("{0} = type::thing('{1}', ${0})", key, target)),
+        )
+        .collect::<Vec<_>>()
+        .join(", ");
+    // Une entité sans champ est créée ou mise à jour sans clause SET
+    let set_clause = match assignments.is_empty() {
+        true => String::new(),
+        false => format!(" SET {}", assignments),
+    };
+
+    let input_bindings = info
+        .fields
+        .iter()
+        .map(|(name, _, _)| format!("            .bind((\"{0}\", input.{0}))", name))
+        .chain(
+            foreign_keys
+                .iter()
+                .map(|(key, _)| format!("            .bind((\"{0}\", input.{0}.0))", key)),
+        )
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"use surrealdb::engine::remote::ws::Client;
+use surrealdb::Surreal;
+use crate::entities::base_entity::SortOrder;
+use crate::entities::{module_name}::{{
+    {pascal_name}, {pascal_name}Filter, {pascal_name}Input, {pascal_name}Pagination, {pascal_name}Sort,
+    {pascal_name}SortField,
+}};
+
+/// Nombre d'enregistrements par page par défaut, et maximum autorisé
+const DEFAULT_PER_PAGE: u32 = 20;
+const MAX_PER_PAGE: u32 = 100;
+
+/// Accès SurrealDB à la table `{table}` ; les enregistrements supprimés ont un `deleted_at`
+pub struct {pascal_name}Repository<'a> {{
+    db: &'a Surreal<Client>,
+}}
+
+impl<'a> {pascal_name}Repository<'a> {{
+    pub fn new(db: &'a Surreal<Client>) -> Self {{
+        Self {{ db }}
+    }}
+
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<{pascal_name}>, surrealdb::Error> {{
+        let mut records: Vec<{pascal_name}> = self
+            .db
+            .query("SELECT * FROM type::thing('{table}', $id) WHERE deleted_at = NONE")
+            .bind(("id", id.to_string()))
+            .await?
+            .take(0)?;
+        Ok(records.pop())
+    }}
+
+    pub async fn find_all(
+        &self,
+        filter: Option<{pascal_name}Filter>,
+        sort: Option<{pascal_name}Sort>,
+        pagination: Option<{pascal_name}Pagination>,
+    ) -> Result<Vec<{pascal_name}>, surrealdb::Error> {{
+        let filter = filter.unwrap_or_default();
+        let pagination = pagination.unwrap_or_default();
+
+        let mut conditions = Vec::new();
+        if !filter.include_deleted.unwrap_or(false) {{
+            conditions.push("deleted_at = NONE");
+        }}
+{filters}
+
+        let (order_by, order) = match sort {{
+            Some(sort) => (
+                match sort.field {{
+                    {pascal_name}SortField::CreatedAt => "created_at",
+                    {pascal_name}SortField::UpdatedAt => "updated_at",
+{sort_fields}
+                }},
+                match sort.order {{
+                    SortOrder::Asc => "ASC",
+                    SortOrder::Desc => "DESC",
+                }},
+            ),
+            None => ("created_at", "DESC"),
+        }};
+
+        // Le curseur est l'identifiant du dernier enregistrement reçu : la page suivante reprend
+        // après sa valeur de tri, les égalités étant départagées par l'identifiant
+        let cursor = pagination.cursor;
+        let cursor_value = format!(
+            "(SELECT VALUE {{}} FROM ONLY type::thing('{table}', $cursor))",
+            order_by
+        );
+        let cursor_condition = format!(
+            "({{0}} {{1}} {{2}} OR ({{0}} = {{2}} AND id {{1}} type::thing('{table}', $cursor)))",
+            order_by,
+            if order == "ASC" {{ ">" }} else {{ "<" }},
+            cursor_value
+        );
+        if cursor.is_some() {{
+            conditions.push(&cursor_condition);
+        }}
+
+        let limit = pagination
+            .per_page
+            .unwrap_or(DEFAULT_PER_PAGE)
+            .clamp(1, MAX_PER_PAGE);
+        let start = match cursor {{
+            Some(_) => 0,
+            None => (pagination.page.unwrap_or(1).max(1) - 1) * limit,
+        }};
+
+        let mut sql = String::from("SELECT * FROM {table}");
+        if !conditions.is_empty() {{
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }}
+        sql.push_str(&format!(
+            " ORDER BY {{0}} {{1}}, id {{1}} LIMIT $limit START $start",
+            order_by, order
+        ));
+
+        self.db
+            .query(sql)
+{filter_bindings}
+            .bind(("cursor", cursor))
+            .bind(("limit", limit))
+            .bind(("start", start))
+            .await?
+            .take(0)
+    }}
+
+    pub async fn create(&self, input: {pascal_name}Input) -> Result<Option<{pascal_name}>, surrealdb::Error> {{
+        let mut records: Vec<{pascal_name}> = self
+            .db
+            .query("CREATE {table}{set_clause}")
+{input_bindings}
+            .await?
+            .take(0)?;
+        Ok(records.pop())
+    }}
+
+    pub async fn update(
+        &self,
+        id: &str,
+        input: {pascal_name}Input,
+    ) -> Result<Option<{pascal_name}>, surrealdb::Error> {{
+        let mut records: Vec<{pascal_name}> = self
+            .db
+            .query("UPDATE type::thing('{table}', $id){set_clause} WHERE deleted_at = NONE")
+            .bind(("id", id.to_string()))
+{input_bindings}
+            .await?
+            .take(0)?;
+        Ok(records.pop())
+    }}
+
+    /// Soft delete : renseigne `deleted_at`, `false` si l'enregistrement n'existe pas ou est déjà supprimé
+    pub async fn soft_delete(&self, id: &str) -> Result<bool, surrealdb::Error> {{
+        let deleted: Vec<{pascal_name}> = self
+            .db
+            .query("UPDATE type::thing('{table}', $id) SET deleted_at = time::now() WHERE deleted_at = NONE")
+            .bind(("id", id.to_string()))
+            .await?
+            .take(0)?;
+        Ok(!deleted.is_empty())
+    }}
+}}
+"#
+    )
+}
+
+/// Génère le service : validation des inputs puis appel du repository
+pub fn generate_service_content(info: &EntityInfo) -> String {
+    let pascal_name = info.name.to_case(Case::Pascal);
+    let module_name = module_name(&info.name);
+
+    format!(
+        r#"use async_graphql::{{Error, Result}};
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::Surreal;
+use validator::Validate;
+use crate::entities::{module_name}::{{
+    {pascal_name}, {pascal_name}Filter, {pascal_name}Input, {pascal_name}Pagination, {pascal_name}Sort,
+}};
+use crate::repositories::{module_name}::{pascal_name}Repository;
+
+pub struct {pascal_name}Service<'a> {{
+    repository: {pascal_name}Repository<'a>,
+}}
+
+impl<'a> {pascal_name}Service<'a> {{
+    pub fn new(db: &'a Surreal<Client>) -> Self {{
+        Self {{
+            repository: {pascal_name}Repository::new(db),
+        }}
+    }}
+
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<{pascal_name}>> {{
+        Ok(self.repository.find_by_id(id).await?)
+    }}
+
+    pub async fn find_all(
+        &self,
+        filter: Option<{pascal_name}Filter>,
+        sort: Option<{pascal_name}Sort>,
+        pagination: Option<{pascal_name}Pagination>,
+    ) -> Result<Vec<{pascal_name}>> {{
+        Ok(self.repository.find_all(filter, sort, pagination).await?)
+    }}
+
+    pub async fn create(&self, input: {pascal_name}Input) -> Result<{pascal_name}> {{
+        input.validate()?;
+        self.repository
+            .create(input)
+            .await?
+            .ok_or_else(|| Error::new("Failed to create {pascal_name}"))
+    }}
+
+    pub async fn update(&self, id: &str, input: {pascal_name}Input) -> Result<{pascal_name}> {{
+        input.validate()?;
+        self.repository
+            .update(id, input)
+            .await?
+            .ok_or_else(|| Error::new(format!("{pascal_name} {{}} not found", id)))
+    }}
+
+    pub async fn delete(&self, id: &str) -> Result<bool> {{
+        Ok(self.repository.soft_delete(id).await?)
+    }}
+}}
+"#
+    )
+}
+
+/// Conditions des filtres de champs, récursivement pour les objets imbriqués
+/// (`address.street` lié à `$address_street_contains`)
+fn field_clauses(
+    fields: &[Field],
+    info: &EntityInfo,
+    path: &str,
+    source: &str,
+) -> Vec<FilterClause> {
+    let mut clauses = Vec::new();
+
+    for (name, type_, _) in fields {
+        if scalar::is_password(type_) {
+            continue;
+        }
+        let field_path = format!("{}{}", path, name);
+        let param = field_path.replace('.', "_");
+        let member = |suffix: &str| filter_member(source, &format!("{}{}", name, suffix));
+        let mut clause = |suffix: &str, condition: String| {
+            clauses.push(FilterClause {
+                param: format!("{}{}", param, suffix),
+                value: member(suffix),
+                condition,
+            })
+        };
+
+        if let Some(object) = find_object(&info.objects, type_) {
+            let nested_source = match source {
+                "filter" => format!("filter.{}.as_ref()", name),
+                _ => format!("{}.and_then(|f| f.{}.as_ref())", source, name),
+            };
+            clauses.extend(field_clauses(
+                &object.fields,
+                info,
+                &format!("{}.", field_path),
+                &nested_source,
+            ));
+            continue;
+        }
+
+        if find_enum(&info.enums, type_).is_some() {
+            clause("_in", format!("{} INSIDE ${}_in", field_path, param));
+            clause(
+                "_not_in",
+                format!("{} NOT IN ${}_not_in", field_path, param),
+            );
+            continue;
+        }
+
+        match type_.as_str() {
+            "String" => clause(
+                "_contains",
+                format!(
+                    "string::contains(string::lowercase({}), string::lowercase(${}_contains))",
+                    field_path, param
+                ),
+            ),
+            "i32" | "i64" => {
+                clause("_min", format!("{} >= ${}_min", field_path, param));
+                clause("_max", format!("{} <= ${}_max", field_path, param));
+            }
+            "DateTime" | "DateTime<Utc>" => {
+                clause("", format!("{} = <datetime> ${}", field_path, param))
+            }
+            _ => clause("", format!("{} = ${}", field_path, param)),
+        }
+    }
+
+    clauses
+}
+
+/// Conditions des filtres de relations (`has_user`, `comment_count_min`, `tag_ids`)
+fn relation_clauses(info: &EntityInfo) -> Vec<FilterClause> {
+    let foreign_key = format!("{}_id", module_name(&info.name));
+    let children = |target: &str| {
+        format!(
+            "count((SELECT VALUE id FROM {} WHERE {} = $parent.id))",
+            table_name(target),
+            foreign_key
+        )
+    };
+    let clause = |param: String, condition: String| FilterClause {
+        value: format!("filter.{}.clone()", param),
+        param,
+        condition,
+    };
+
+    let mut clauses = Vec::new();
+    for relation in &info.relations {
+        match relation {
+            RelationType::BelongsTo(target) => {
+                let target = module_name(target);
+                clauses.push(clause(
+                    format!("has_{}", target),
+                    format!("({}_id != NONE) = $has_{}", target, target),
+                ));
+            }
+            RelationType::HasOne(target) => {
+                let param = format!("has_{}", module_name(target));
+                let condition = format!("({} > 0) = ${}", children(target), param);
+                clauses.push(clause(param, condition));
+            }
+            RelationType::HasMany(target) => {
+                let lower = module_name(target);
+                clauses.push(clause(
+                    format!("has_{}", lower),
+                    format!("({} > 0) = $has_{}", children(target), lower),
+                ));
+                clauses.push(clause(
+                    format!("{}_count_min", lower),
+                    format!("{} >= ${}_count_min", children(target), lower),
+                ));
+                clauses.push(clause(
+                    format!("{}_count_max", lower),
+                    format!("{} <= ${}_count_max", children(target), lower),
+                ));
+            }
+            RelationType::ManyToMany(target, edge) => {
+                let param = format!("{}_ids", module_name(target));
+                let condition = format!(
+                    "count(->{}[WHERE record::id(out) INSIDE ${}]) > 0",
+                    edge, param
+                );
+                clauses.push(clause(param, condition));
+            }
+        }
+    }
+    clauses
+}
+
+/// Clés étrangères `belongsTo` et table de leur cible (`user_id`, `users`) ; l'input les reçoit
+/// sous la forme d'un identifiant sans le nom de la table
+fn foreign_keys(info: &EntityInfo) -> Vec<(String, String)> {
+    info.relations
+        .iter()
+        .filter_map(|relation| match relation {
+            RelationType::BelongsTo(target) => {
+                Some((format!("{}_id", module_name(target)), table_name(target)))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Valeur d'un champ du filtre ; `source` est `filter` ou un `Option<&XFilter>` imbriqué
+fn filter_member(source: &str, member: &str) -> String {
+    match source {
+        "filter" => format!("filter.{}.clone()", member),
+        _ => format!("{}.and_then(|f| f.{}.clone())", source, member),
+    }
+}
+
+fn update_mod(mod_path: &str, module_name: &str) -> Result<(), String> {
+    let mut content = fs::read_to_string(mod_path).unwrap_or_default();
+
+    if !content.contains(&format!("pub mod {};", module_name)) {
+        content.push_str(&format!("pub mod {};\n", module_name));
+        fs::write(mod_path, content)
+            .map_err(|e| format!("Failed to update {}: {}", mod_path, e))?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 // src/main.rs
 mod cli;
 mod commands;
+mod db;
 mod generators;
 mod templates;
 mod tests;
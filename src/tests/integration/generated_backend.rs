@@ -0,0 +1,87 @@
+// src/tests/integration/generated_backend.rs
+use crate::generators::backend;
+use assert_cmd::Command as CliCommand;
+use std::path::Path;
+use std::process::Command;
+use tempfile::tempdir;
+
+/// Variable d'environnement activant les tests qui compilent un projet généré
+const SLOW_TESTS: &str = "NEBULIS_SLOW_TESTS";
+
+/// Lance la CLI depuis la racine du projet, où travaillent les générateurs
+fn nebulis(project: &Path, args: &[&str]) {
+    CliCommand::cargo_bin("nebulis")
+        .unwrap()
+        .current_dir(project)
+        .args(args)
+        .assert()
+        .success();
+}
+
+/// Un backend fraîchement créé, avec des entités reliées par `belongsTo` et `manyToMany`, leurs
+/// services et leurs resolvers, doit passer `cargo check` ; les dépendances du backend sont
+/// téléchargées puis compilées dans `target/generated_backend`
+#[test]
+fn test_generated_entity_compiles() {
+    if std::env::var_os(SLOW_TESTS).is_none() {
+        eprintln!(
+            "skipped: set {}=1 to compile a generated backend",
+            SLOW_TESTS
+        );
+        return;
+    }
+
+    let temp_dir = tempdir().unwrap();
+    let project = temp_dir.path().join("blog");
+    backend::create_structure(project.to_str().unwrap());
+
+    nebulis(
+        &project,
+        &[
+            "generate",
+            "entity",
+            "Author",
+            "--fields",
+            "name:String|unique",
+        ],
+    );
+    nebulis(
+        &project,
+        &["generate", "entity", "Tag", "--fields", "label:String"],
+    );
+    nebulis(
+        &project,
+        &[
+            "generate",
+            "entity",
+            "Article",
+            "--fields",
+            "title:String|required|min_length=3|pattern=^[A-Z]",
+            "contact:Email",
+            "price:Money|min=0",
+            "status:enum(draft,published)=draft",
+            "address?:Address{street:String,city:String|required}",
+            "keywords:Vec<String>|max_length=5",
+            "views:i32=0|min=0",
+            "published_at?:DateTime",
+            "author_key:Password",
+            "belongsTo->Author",
+            "manyToMany->Tag via tagged",
+        ],
+    );
+    for entity in ["Author", "Tag", "Article"] {
+        nebulis(&project, &["generate", "service", entity]);
+        nebulis(&project, &["generate", "resolver", entity]);
+    }
+
+    let status = Command::new(std::env::var("CARGO").unwrap_or("cargo".into()))
+        .arg("check")
+        .current_dir(project.join("backend"))
+        .env(
+            "CARGO_TARGET_DIR",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/target/generated_backend"),
+        )
+        .status()
+        .unwrap();
+    assert!(status.success(), "generated backend does not compile");
+}
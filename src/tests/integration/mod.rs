@@ -1,14 +1,23 @@
+mod generated_backend;
+
+use crate::tests::utils::assert_project_structure;
 use assert_cmd::Command;
 use predicates::prelude::*;
+use tempfile::tempdir;
 
 #[test]
+#[ignore = "runs git and npx to create the Remix frontend"]
 fn test_cli_new() -> Result<(), Box<dyn std::error::Error>> {
-    let mut cmd = Command::cargo_bin("nebulis-cli")?;
+    let temp_dir = tempdir()?;
+    let mut cmd = Command::cargo_bin("nebulis")?;
 
-    cmd.arg("new").arg("test_project");
-    cmd.assert()
-        .success()
-        .stdout(predicate::str::contains("Creating Nebulis project"));
+    cmd.current_dir(temp_dir.path())
+        .arg("new")
+        .arg("test_project");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "Creating Nebulis Full Stack Project",
+    ));
+    assert_project_structure(&temp_dir.path().join("test_project"));
 
     Ok(())
 }
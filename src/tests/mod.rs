@@ -1,8 +1,6 @@
 #[cfg(test)]
-mod tests {
-    use assert_fs::prelude::*;
-    use predicates::prelude::*;
-    use std::process::Command;
-
-    // Tests...
-}
+mod integration;
+#[cfg(test)]
+mod unit;
+#[cfg(test)]
+mod utils;
@@ -0,0 +1,34 @@
+use crate::generators::entity::{preview, EntityInfo};
+use crate::generators::fields::parse;
+
+#[test]
+fn test_generated_entity_round_trips() {
+    let fields = [
+        "title:String|required|unique|min_length=3|max_length=80|pattern=^[A-Z]",
+        "contact:Email|email",
+        "views:i32=0|min=0|max=10",
+        "summary?:String|required",
+        "secret:Password",
+        "belongsTo->BlogPost",
+    ]
+    .map(String::from);
+    let info = parse("article", &fields).unwrap();
+
+    let entity = preview(&info).unwrap();
+    assert!(entity.contains(
+        "#[validate(length(min = 3, max = 80), regex(path = \"ARTICLE_TITLE_PATTERN\"))]"
+    ));
+    assert!(entity.contains("/// Validate: email\n    pub contact: Email,"));
+    assert!(entity.contains("#[validate(range(min = 0, max = 10))]"));
+    assert!(entity.contains("#[validate(required)]\n    pub summary: Option<String>,"));
+    assert!(entity.contains("#[graphql(skip)]\n    pub blog_post_id: Option<Thing>,"));
+    // Le hash n'est sérialisé ni par GraphQL ni par serde (logs, JSON)
+    assert!(entity.contains(
+        "#[graphql(skip_output)]\n    #[serde(skip_serializing)]\n    pub secret: Password,"
+    ));
+
+    // Les règles que validator ne vérifie pas sont relues depuis leurs commentaires
+    let parsed = EntityInfo::parse(&entity).unwrap();
+    assert_eq!(parsed.fields, info.fields);
+    assert_eq!(parsed.relations, info.relations);
+}
@@ -0,0 +1,28 @@
+use crate::generators::entity::RelationType;
+use crate::generators::entity_builder::build;
+use std::io::Cursor;
+
+#[test]
+fn test_entity_builder_reasks_invalid_answers() {
+    // Un type inconnu puis une règle inconnue sont redemandés avant de passer à la suite
+    let answers = "title\nStrin\n1\n\n\nrequired|foo\nrequired\nstatus\nenum(draft,published)\ny\ndraft\n\n\nbelongsTo->User\n\ny\n";
+    let mut output = Vec::new();
+    let info = build("article", &mut Cursor::new(answers), &mut output)
+        .unwrap()
+        .unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("Invalid type: Strin"));
+    assert!(output.contains("Unknown validation rule: foo"));
+    assert!(output.contains("pub struct Article {"));
+
+    assert_eq!(info.fields[0].1, "String");
+    assert!(info.fields[0].2.required);
+    assert_eq!(info.fields[1].1, "Option<ArticleStatus>");
+    assert_eq!(info.fields[1].2.default.as_deref(), Some("draft"));
+    assert_eq!(info.relations, [RelationType::BelongsTo("User".into())]);
+
+    // Sans confirmation, rien n'est généré
+    let declined = build("article", &mut Cursor::new("\n\nn\n"), &mut Vec::new()).unwrap();
+    assert!(declined.is_none());
+}
@@ -0,0 +1,82 @@
+use crate::generators::entity::RelationType;
+use crate::generators::fields::parse;
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_fields_grammar() {
+    let info = parse(
+        "post",
+        &args(&[
+            "title:String|required|min_length=3",
+            r#"slug:String|pattern="^[a-z, |-]+$""#,
+            "bio?:String tags:Vec<String>",
+            "views:i32=0",
+            "status:enum(Draft, Published)=draft",
+            "address:Address{street:String, city:String}",
+            "manyToMany->Tag via tagged",
+        ]),
+    )
+    .unwrap();
+
+    let types = info
+        .fields
+        .iter()
+        .map(|(name, type_, _)| format!("{}:{}", name, type_))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        [
+            "title:String",
+            "slug:String",
+            "bio:Option<String>",
+            "tags:Vec<String>",
+            "views:i32",
+            "status:PostStatus",
+            "address:Address",
+        ]
+    );
+    assert_eq!(info.fields[0].2.min_length, Some(3));
+    assert_eq!(info.fields[1].2.pattern.as_deref(), Some("^[a-z, |-]+$"));
+    assert_eq!(info.fields[4].2.default.as_deref(), Some("0"));
+    assert_eq!(info.objects[0].fields.len(), 2);
+    assert_eq!(
+        info.relations,
+        [RelationType::ManyToMany("Tag".into(), "tagged".into())]
+    );
+
+    // Entre guillemets, seuls \" \' et \\ sont des échappements
+    let info = parse("code", &args(&[r#"value:String|pattern="^\d+ \"x\" \\$""#])).unwrap();
+    assert_eq!(info.fields[0].2.pattern.as_deref(), Some(r#"^\d+ "x" \$"#));
+
+    // Règles séparées par des espaces, comme avec le découpage de clap
+    let info = parse("user", &args(&["email:Email|required", "unique"])).unwrap();
+    assert!(info.fields[0].2.required && info.fields[0].2.unique);
+}
+
+#[test]
+fn test_fields_errors_point_at_the_column() {
+    let error = parse("user", &args(&["name:String", "age:i32=old"])).unwrap_err();
+    assert_eq!(
+        error,
+        "Invalid default value old for type i32 (column 21)\n  name:String age:i32=old\n                      ^"
+    );
+
+    let error = parse("user", &args(&["name String"])).unwrap_err();
+    assert!(error.ends_with("  name String\n      ^"), "{}", error);
+
+    let error = parse("user", &args(&["title?:Option<String>"])).unwrap_err();
+    assert_eq!(
+        error,
+        "Field title is marked optional twice: use title?:T or title:Option<T> (column 6)\n  title?:Option<String>\n       ^"
+    );
+
+    let error = parse("user", &args(&["name:String", "name:i32"])).unwrap_err();
+    assert!(
+        error.starts_with("Field name is declared twice (column 13)"),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,35 @@
+use crate::generators::fields::parse;
+use crate::generators::frontend::generate_edit_route;
+
+#[test]
+fn test_edit_route_sends_complete_input() {
+    let args = [
+        "body:String",
+        "score?:i32",
+        "address:Address{street:String,zip?:u32}",
+        "tags:Vec<String>",
+        "scores:Vec<i32>",
+        "meta:Json",
+        "belongsTo->User",
+    ]
+    .map(String::from);
+    let info = parse("Review", &args).unwrap();
+    let route = generate_edit_route(&info);
+
+    // Les nombres optionnels restent des nombres, ou null lorsqu'ils sont vides
+    assert!(route.contains(r#"score: form.get("score") ? Number(form.get("score")) : null,"#));
+    // L'objet imbriqué et la clé étrangère requis par ReviewInput sont envoyés
+    assert!(route.contains(
+        "    address: {\n      street: form.get(\"address.street\"),\n      zip: form.get(\"address.zip\") ? Number(form.get(\"address.zip\")) : null,\n    },"
+    ));
+    assert!(route.contains(r#"userId: form.get("userId"),"#));
+    // Les listes sont découpées sur les virgules, le Json est relu avant l'envoi
+    assert!(route.contains(
+        r#"tags: String(form.get("tags") ?? "").split(",").map((item) => item.trim()).filter(Boolean),"#
+    ));
+    assert!(route.contains(r#".filter(Boolean).map(Number),"#));
+    assert!(route.contains(r#"meta: JSON.parse(String(form.get("meta"))),"#));
+    assert!(route.contains(r#"defaultValue={(review.tags ?? []).join(", ")}"#));
+    assert!(route.contains("address { street zip }"));
+    assert!(route.contains(r#"<input type="number" name="address.zip""#));
+}
@@ -0,0 +1,176 @@
+use crate::db;
+use crate::db::migrator;
+use crate::db::schema::{FieldDef, Schema, TableSchema};
+use crate::db::transaction::{split_statements, Transaction};
+use crate::generators::fields;
+use crate::generators::migration::{self, generate_migration_files, MigrationOptions};
+use crate::tests::utils::memory_config;
+use surrealdb::engine::any::Any;
+use surrealdb::Surreal;
+use tempfile::tempdir;
+
+async fn run(db: &Surreal<Any>, sql: &str) {
+    let mut tx = Transaction::new();
+    for (i, statement) in split_statements(sql).iter().enumerate() {
+        tx.push("Migration", i + 1, statement);
+    }
+    tx.commit(db).await.unwrap();
+}
+
+#[test]
+fn test_migrations_generated_in_the_same_second_get_distinct_versions() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+
+    generate_migration_files(
+        root,
+        "create_posts",
+        "DEFINE TABLE posts;",
+        "REMOVE TABLE posts;",
+    )
+    .unwrap();
+    generate_migration_files(
+        root,
+        "create_tags",
+        "DEFINE TABLE tags;",
+        "REMOVE TABLE tags;",
+    )
+    .unwrap();
+
+    let migrations = migrator::discover(root).unwrap();
+    let names = migrations
+        .iter()
+        .map(|m| m.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["create_posts", "create_tags"]);
+    assert!(migrations[0].version < migrations[1].version);
+}
+
+#[test]
+fn test_typed_column_round_trips_through_database() {
+    let options = MigrationOptions {
+        type_: Some("int".to_string()),
+        default: Some("0".to_string()),
+        assert: Some("$value >= 0".to_string()),
+        ..Default::default()
+    };
+    let add = migration::operation("add_age_to_users", &options, &Schema::default()).unwrap();
+    let age = FieldDef {
+        kind: "int".to_string(),
+        default: Some("0".to_string()),
+        value: None,
+        assert: Some("$value >= 0".to_string()),
+    };
+    assert_eq!(
+        add.up_sql(),
+        "DEFINE FIELD age ON users TYPE int DEFAULT 0 ASSERT $value >= 0;"
+    );
+
+    // La migration `down` d'une suppression recrée la définition connue du snapshot
+    let mut snapshot = Schema::default();
+    let mut users = TableSchema::default();
+    users.fields.insert("age".to_string(), age.clone());
+    snapshot.tables.insert("users".to_string(), users);
+    let remove = migration::operation(
+        "remove_age_from_users",
+        &MigrationOptions::default(),
+        &snapshot,
+    )
+    .unwrap();
+
+    let age_after = |schema: &Schema| schema.tables["users"].fields.get("age").cloned();
+    let (added, add_reverted, removed, restored) = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        run(&db, "DEFINE TABLE users SCHEMAFULL;").await;
+
+        run(&db, &add.up_sql()).await;
+        let added = age_after(&Schema::from_database(&db).await.unwrap());
+        run(&db, &add.down_sql()).await;
+        let add_reverted = age_after(&Schema::from_database(&db).await.unwrap());
+
+        run(&db, &add.up_sql()).await;
+        run(&db, &remove.up_sql()).await;
+        let removed = age_after(&Schema::from_database(&db).await.unwrap());
+        run(&db, &remove.down_sql()).await;
+        let restored = age_after(&Schema::from_database(&db).await.unwrap());
+        (added, add_reverted, removed, restored)
+    })
+    .unwrap();
+
+    assert_eq!(added, Some(age.clone()));
+    assert_eq!(add_reverted, None);
+    assert_eq!(removed, None);
+    assert_eq!(restored, Some(age));
+}
+
+#[test]
+fn test_backup_restores_removed_column_values() {
+    let mut snapshot = Schema::default();
+    let mut users = TableSchema::default();
+    users
+        .fields
+        .insert("age".to_string(), FieldDef::new("option<int>"));
+    snapshot.tables.insert("users".to_string(), users);
+    let options = MigrationOptions {
+        backup: true,
+        ..Default::default()
+    };
+    let remove = migration::operation("remove_age_from_users", &options, &snapshot).unwrap();
+
+    let ages = |db: &Surreal<Any>| {
+        let db = db.clone();
+        async move {
+            let mut response = db
+                .query("SELECT VALUE age FROM users:1, users:2, users:3")
+                .await
+                .unwrap();
+            response.take::<Vec<Option<i64>>>(0).unwrap()
+        }
+    };
+    let (removed, restored, tables) = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        run(
+            &db,
+            "DEFINE TABLE users SCHEMAFULL;\n\
+             DEFINE FIELD age ON users TYPE option<int>;\n\
+             CREATE users:1 SET age = 30;\n\
+             CREATE users:2 SET age = 41;\n\
+             CREATE users:3;",
+        )
+        .await;
+
+        run(&db, &remove.up_sql()).await;
+        let removed = ages(&db).await;
+        run(&db, &remove.down_sql()).await;
+        let restored = ages(&db).await;
+
+        let mut info = db.query("INFO FOR DB").await.unwrap();
+        let info: Option<serde_json::Value> = info.take(0).unwrap();
+        (removed, restored, info.unwrap()["tables"].clone())
+    })
+    .unwrap();
+
+    assert_eq!(removed, vec![None, None, None]);
+    assert_eq!(restored, vec![Some(30), Some(41), None]);
+    assert!(tables.get("_nebulis_backup_users_age").is_none());
+}
+
+#[test]
+fn test_added_relation_points_at_the_entity_table() {
+    let add = migration::operation(
+        "add_relation_posts_to_user",
+        &MigrationOptions::default(),
+        &Schema::default(),
+    )
+    .unwrap();
+
+    // Même clé étrangère qu'un `belongsTo->User` déclaré sur l'entité
+    let info = fields::parse("Post", &["belongsTo->User".to_string()]).unwrap();
+    let define = TableSchema::from_entity("posts", &info).define("posts");
+    let user_id = define
+        .iter()
+        .find(|statement| statement.starts_with("DEFINE FIELD user_id"))
+        .unwrap();
+    assert_eq!(user_id, "DEFINE FIELD user_id ON posts TYPE record<users>;");
+    assert_eq!(add.up_sql().lines().next().unwrap(), user_id);
+}
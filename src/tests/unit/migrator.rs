@@ -0,0 +1,455 @@
+use crate::db::migrator::{MigrationState, RollbackTarget, TransactionMode};
+use crate::db::transaction::split_statements;
+use crate::db::{self, ledger, migrator};
+use crate::tests::utils::memory_config;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn write_migration(root: &Path, name: &str, version: &str, up: &str) {
+    fs::create_dir_all(root.join("database/schema")).unwrap();
+    fs::write(
+        root.join(format!("database/schema/{}_{}.up.surql", version, name)),
+        up,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_apply_pending_in_version_order() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_migration(
+        root,
+        "add_age_to_users",
+        "20240102000000",
+        "DEFINE FIELD age ON users TYPE int;",
+    );
+    write_migration(
+        root,
+        "create_users",
+        "20240101000000",
+        "DEFINE TABLE users SCHEMAFULL;",
+    );
+
+    let (first, second, applied) = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        let first = migrator::apply_pending(&db, root, TransactionMode::PerMigration, None)
+            .await
+            .unwrap();
+        let second = migrator::apply_pending(&db, root, TransactionMode::PerMigration, None)
+            .await
+            .unwrap();
+        (first, second, ledger::entries(&db).await.unwrap())
+    })
+    .unwrap();
+
+    assert_eq!(first, vec!["create_users", "add_age_to_users"]);
+    assert!(second.is_empty());
+    assert_eq!(applied.len(), 2);
+    assert_eq!(applied[0].name, "create_users");
+    assert_eq!(
+        applied[0].checksum,
+        ledger::checksum("DEFINE TABLE users SCHEMAFULL;")
+    );
+}
+
+#[test]
+fn test_apply_pending_stops_at_first_failure() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_migration(
+        root,
+        "create_users",
+        "20240101000000",
+        "DEFINE TABLE users SCHEMAFULL;",
+    );
+    write_migration(root, "broken", "20240102000000", "THROW 'boom';");
+    write_migration(
+        root,
+        "create_posts",
+        "20240103000000",
+        "DEFINE TABLE posts SCHEMAFULL;",
+    );
+
+    let (result, applied) = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        let result = migrator::apply_pending(&db, root, TransactionMode::PerMigration, None).await;
+        (result, ledger::entries(&db).await.unwrap())
+    })
+    .unwrap();
+
+    assert!(result.unwrap_err().contains("Migration broken failed"));
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].name, "create_users");
+}
+
+#[test]
+fn test_rollback_runs_down_files_in_reverse() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_migration(
+        root,
+        "create_users",
+        "20240101000000",
+        "DEFINE TABLE users SCHEMAFULL;",
+    );
+    write_migration(
+        root,
+        "create_posts",
+        "20240102000000",
+        "DEFINE TABLE posts SCHEMAFULL;",
+    );
+    fs::write(
+        root.join("database/schema/20240101000000_create_users.down.surql"),
+        "REMOVE TABLE users;",
+    )
+    .unwrap();
+    fs::write(
+        root.join("database/schema/20240102000000_create_posts.down.surql"),
+        "REMOVE TABLE posts;",
+    )
+    .unwrap();
+
+    let (rolled_back, applied) = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        migrator::apply_pending(&db, root, TransactionMode::PerMigration, None)
+            .await
+            .unwrap();
+        let rolled_back = migrator::rollback(&db, root, &RollbackTarget::Steps(2))
+            .await
+            .unwrap();
+        (rolled_back, ledger::entries(&db).await.unwrap())
+    })
+    .unwrap();
+
+    assert_eq!(rolled_back, vec!["create_posts", "create_users"]);
+    assert!(applied.is_empty());
+}
+
+#[test]
+fn test_failed_rollback_is_cancelled_and_ledger_unchanged() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_migration(
+        root,
+        "create_posts",
+        "20240101000000",
+        "DEFINE TABLE posts SCHEMAFULL;",
+    );
+    fs::write(
+        root.join("database/schema/20240101000000_create_posts.down.surql"),
+        "REMOVE TABLE posts;\nTHROW 'boom';",
+    )
+    .unwrap();
+
+    let (result, applied, posts_table) = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        migrator::apply_pending(&db, root, TransactionMode::PerMigration, None)
+            .await
+            .unwrap();
+        let result = migrator::rollback(&db, root, &RollbackTarget::Steps(1)).await;
+        let mut info = db.query("INFO FOR DB").await.unwrap();
+        let info: Option<serde_json::Value> = info.take(0).unwrap();
+        let posts_table = info.unwrap()["tables"].get("posts").cloned();
+        (result, ledger::entries(&db).await.unwrap(), posts_table)
+    })
+    .unwrap();
+
+    let error = result.unwrap_err();
+    assert!(
+        error.contains("Rollback create_posts failed at statement 2"),
+        "{}",
+        error
+    );
+    assert_eq!(applied.len(), 1);
+    assert!(posts_table.is_some());
+}
+
+#[test]
+fn test_rollback_refuses_missing_down_file() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_migration(
+        root,
+        "create_users",
+        "20240101000000",
+        "DEFINE TABLE users SCHEMAFULL;",
+    );
+
+    let (result, applied) = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        migrator::apply_pending(&db, root, TransactionMode::PerMigration, None)
+            .await
+            .unwrap();
+        let result = migrator::rollback(&db, root, &RollbackTarget::Steps(1)).await;
+        (result, ledger::entries(&db).await.unwrap())
+    })
+    .unwrap();
+
+    assert!(result.unwrap_err().contains("is missing"));
+    assert_eq!(applied.len(), 1);
+}
+
+#[test]
+fn test_status_reports_pending_drift_and_missing_files() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_migration(
+        root,
+        "create_users",
+        "20240101000000",
+        "DEFINE TABLE users SCHEMAFULL;",
+    );
+    write_migration(
+        root,
+        "create_tags",
+        "20240102000000",
+        "DEFINE TABLE tags SCHEMAFULL;",
+    );
+
+    let applied = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        migrator::apply_pending(&db, root, TransactionMode::PerMigration, None)
+            .await
+            .unwrap();
+        ledger::entries(&db).await.unwrap()
+    })
+    .unwrap();
+
+    fs::write(
+        root.join("database/schema/20240101000000_create_users.up.surql"),
+        "DEFINE TABLE users SCHEMALESS;",
+    )
+    .unwrap();
+    fs::remove_file(root.join("database/schema/20240102000000_create_tags.up.surql")).unwrap();
+    write_migration(
+        root,
+        "create_posts",
+        "20240103000000",
+        "DEFINE TABLE posts SCHEMAFULL;",
+    );
+
+    let rows = migrator::status(root, &applied).unwrap();
+    let states = rows
+        .iter()
+        .map(|row| (row.name.as_str(), row.state.clone(), row.drifted))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        states,
+        vec![
+            ("create_users", MigrationState::Applied, true),
+            ("create_tags", MigrationState::MissingFile, false),
+            ("create_posts", MigrationState::Pending, false),
+        ]
+    );
+}
+
+#[test]
+fn test_failed_migration_is_cancelled_and_not_recorded() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_migration(
+        root,
+        "create_users",
+        "20240101000000",
+        "DEFINE TABLE users SCHEMAFULL;\nTHROW 'boom';",
+    );
+
+    let (result, applied, users_table) = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        let result = migrator::apply_pending(&db, root, TransactionMode::PerMigration, None).await;
+        let mut info = db.query("INFO FOR DB").await.unwrap();
+        let info: Option<serde_json::Value> = info.take(0).unwrap();
+        let users_table = info.unwrap()["tables"].get("users").cloned();
+        (result, ledger::entries(&db).await.unwrap(), users_table)
+    })
+    .unwrap();
+
+    let error = result.unwrap_err();
+    assert!(error.contains("Migration create_users failed at statement 2"));
+    assert!(error.contains("THROW 'boom'"));
+    assert!(applied.is_empty());
+    assert!(users_table.is_none());
+}
+
+#[test]
+fn test_batch_mode_cancels_every_pending_migration() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_migration(
+        root,
+        "create_users",
+        "20240101000000",
+        "DEFINE TABLE users SCHEMAFULL;",
+    );
+    write_migration(root, "broken", "20240102000000", "THROW 'boom';");
+
+    let (result, applied) = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        let result = migrator::apply_pending(&db, root, TransactionMode::Batch, None).await;
+        (result, ledger::entries(&db).await.unwrap())
+    })
+    .unwrap();
+
+    assert!(result.unwrap_err().contains("Migration broken failed"));
+    assert!(applied.is_empty());
+}
+
+#[test]
+fn test_split_statements_ignores_comments_strings_and_blocks() {
+    let sql = "// Champs système\n\
+               DEFINE FIELD name ON users TYPE string ASSERT $value != 'a;b';\n\
+               DEFINE FUNCTION fn::greet($n: string) { LET $x = 1; RETURN $n; };\n\
+               -- fin";
+
+    assert_eq!(
+        split_statements(sql),
+        vec![
+            "DEFINE FIELD name ON users TYPE string ASSERT $value != 'a;b'",
+            "DEFINE FUNCTION fn::greet($n: string) { LET $x = 1; RETURN $n; }",
+        ]
+    );
+}
+
+#[test]
+fn test_discover_rejects_unversioned_files() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("database/schema")).unwrap();
+    fs::write(
+        root.join("database/schema/create_users.up.surql"),
+        "DEFINE TABLE users SCHEMAFULL;",
+    )
+    .unwrap();
+
+    let error = migrator::discover(root).unwrap_err();
+    assert!(error.contains("Invalid migration file name: create_users.up.surql"));
+}
+
+/// Entrées de ledger pour des migrations `(version, name)` supposées appliquées
+fn ledger_entries(migrations: &[(&str, &str)]) -> Vec<ledger::LedgerEntry> {
+    migrations
+        .iter()
+        .map(|(version, name)| ledger::LedgerEntry {
+            version: version.to_string(),
+            name: name.to_string(),
+            checksum: String::new(),
+            applied_at: String::new(),
+            duration_ms: 0,
+        })
+        .collect()
+}
+
+#[test]
+fn test_plans_stop_at_target_version() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let migrations = [
+        ("20240101000000", "create_users"),
+        ("20240102000000", "create_posts"),
+        ("20240103000000", "create_tags"),
+    ];
+    for (version, name) in migrations {
+        write_migration(root, name, version, "DEFINE TABLE x;");
+        fs::write(
+            root.join(format!("database/schema/{}_{}.down.surql", version, name)),
+            format!("REMOVE TABLE {};", name),
+        )
+        .unwrap();
+    }
+
+    let up = migrator::plan_up(
+        root,
+        Some("20240102000000"),
+        &ledger_entries(&migrations[..1]),
+    )
+    .unwrap();
+    let down = migrator::plan_down(
+        root,
+        &RollbackTarget::To("20240101000000".into()),
+        &ledger_entries(&migrations),
+    )
+    .unwrap();
+
+    let up_names = up.iter().map(|(m, _)| m.name.as_str()).collect::<Vec<_>>();
+    let down_names = down
+        .iter()
+        .map(|(m, _)| m.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(up_names, vec!["create_posts"]);
+    assert_eq!(down_names, vec!["create_tags", "create_posts"]);
+    assert_eq!(down[0].1, "REMOVE TABLE create_tags;");
+    assert!(migrator::check_target(root, "20991231000000").is_err());
+}
+
+#[test]
+fn test_dry_run_plan_is_what_migrate_executes() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    // Chaque fichier journalise son exécution dans `log`, dans l'ordre
+    for (version, name) in [
+        ("20240101000000", "create_users"),
+        ("20240102000000", "create_posts"),
+        ("20240103000000", "create_tags"),
+    ] {
+        for direction in ["up", "down"] {
+            fs::create_dir_all(root.join("database/schema")).unwrap();
+            fs::write(
+                root.join(format!(
+                    "database/schema/{}_{}.{}.surql",
+                    version, name, direction
+                )),
+                format!(
+                    "CREATE log SET step = '{} {}', n = array::len((SELECT VALUE id FROM log));",
+                    direction, name
+                ),
+            )
+            .unwrap();
+        }
+    }
+
+    let runs = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        migrator::apply_pending(
+            &db,
+            root,
+            TransactionMode::PerMigration,
+            Some("20240101000000"),
+        )
+        .await
+        .unwrap();
+
+        let mut runs = Vec::new();
+        for to in [None, Some("20240102000000")] {
+            db.query("DELETE log").await.unwrap();
+            let applied = ledger::entries(&db).await.unwrap();
+            let (down, up) = migrator::plan_to(root, to, &applied).unwrap();
+            migrator::migrate(&db, root, TransactionMode::PerMigration, to)
+                .await
+                .unwrap();
+
+            let mut response = db
+                .query("SELECT step, n FROM log ORDER BY n")
+                .await
+                .unwrap();
+            let executed: Vec<String> = response.take((0, "step")).unwrap();
+            runs.push((down, up, executed));
+        }
+        runs
+    })
+    .unwrap();
+
+    assert_eq!(runs[0].2, ["up create_posts", "up create_tags"]);
+    assert_eq!(runs[1].2, ["down create_tags"]);
+    for (down, up, executed) in runs {
+        let planned = down
+            .iter()
+            .chain(&up)
+            .flat_map(|(_, sql)| split_statements(sql))
+            .map(|statement| statement.split('\'').nth(1).unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(planned, executed);
+    }
+}
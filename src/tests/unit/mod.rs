@@ -1,4 +1,19 @@
-use crate::generators::{backend, docker, frontend};
+mod entity;
+mod entity_builder;
+mod fields;
+mod frontend;
+mod migration;
+mod migrator;
+mod resolver;
+mod scaffold;
+mod scalar;
+mod schema;
+mod schema_file;
+mod seed;
+mod seeder;
+mod service;
+
+use crate::generators::backend;
 use tempfile::tempdir;
 
 #[test]
@@ -6,7 +21,7 @@ fn test_backend_generator() {
     let temp_dir = tempdir().unwrap();
     let project_name = "test_project";
 
-    backend::create_structure(&temp_dir.path().join(project_name).to_str().unwrap());
+    backend::create_structure(temp_dir.path().join(project_name).to_str().unwrap());
 
     // Vérifier la structure backend
     assert!(temp_dir
@@ -19,4 +34,9 @@ fn test_backend_generator() {
         .join(project_name)
         .join("backend/src/main.rs")
         .exists());
+    assert!(temp_dir
+        .path()
+        .join(project_name)
+        .join("backend/src/graphql/root.rs")
+        .exists());
 }
@@ -0,0 +1,13 @@
+use crate::generators::resolver::root_content;
+
+#[test]
+fn test_root_merges_registered_resolvers() {
+    let root = root_content(&["blog_post".to_string(), "user".to_string()]);
+    assert!(root.contains("pub struct QueryRoot(HealthQuery, BlogPostQuery, UserQuery);"));
+    assert!(root.contains("pub struct MutationRoot(BlogPostMutation, UserMutation);"));
+
+    // Sans resolver, le schéma reste valide : GraphQL interdit un type Mutation sans champ
+    let empty = root_content(&[]);
+    assert!(empty.contains("pub struct QueryRoot(HealthQuery);"));
+    assert!(empty.contains("pub type MutationRoot = EmptyMutation;"));
+}
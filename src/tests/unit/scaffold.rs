@@ -0,0 +1,15 @@
+use crate::generators::migration::created_table;
+use crate::generators::scaffold::check_names;
+
+#[test]
+fn test_scaffold_names_agree_across_generators() {
+    for name in ["BlogPost", "blog_post", "Address", "Status", "User"] {
+        assert!(check_names(name).is_ok(), "{} should scaffold", name);
+    }
+    assert_eq!(
+        created_table("create_blog_posts").as_deref(),
+        Some("blog_posts")
+    );
+    assert!(check_names("1Post").is_err());
+    assert!(check_names("Blog-Post!").is_err());
+}
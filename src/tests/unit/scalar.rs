@@ -0,0 +1,47 @@
+use crate::generators::entity::used_scalars;
+use crate::generators::fields::parse;
+use crate::generators::scalar::ensure_scalars;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_only_used_scalars_are_generated() {
+    let fields = ["contact?:Email", "login:Password", "links:Vec<Url>"].map(String::from);
+    let info = parse("user", &fields).unwrap();
+    let scalars = used_scalars(&info);
+    assert_eq!(scalars, ["Email", "Url", "Password"]);
+
+    let temp_dir = tempdir().unwrap();
+    let backend = temp_dir.path().join("backend");
+    fs::create_dir_all(backend.join("src/graphql")).unwrap();
+    fs::write(
+        backend.join("Cargo.toml"),
+        "[package]\nname = \"blog_backend\"\n\n[dependencies]\nserde = \"1.0\"\n",
+    )
+    .unwrap();
+    fs::write(backend.join("src/graphql/mod.rs"), "pub mod root;\n").unwrap();
+
+    let src = backend.join("src");
+    let src = src.to_str().unwrap();
+    assert_eq!(ensure_scalars(src, &scalars).unwrap().len(), 3);
+    // Relancer la génération ne réécrit ni ne redéclare rien
+    assert!(ensure_scalars(src, &scalars).unwrap().is_empty());
+
+    let scalars_dir = backend.join("src/graphql/scalars");
+    let mut files = fs::read_dir(&scalars_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect::<Vec<_>>();
+    files.sort();
+    assert_eq!(files, ["email.rs", "mod.rs", "password.rs", "url.rs"]);
+
+    let scalars_mod = fs::read_to_string(scalars_dir.join("mod.rs")).unwrap();
+    assert_eq!(scalars_mod.matches("pub use email::Email;").count(), 1);
+    let password = fs::read_to_string(scalars_dir.join("password.rs")).unwrap();
+    assert!(password.contains("#[Scalar]") && password.contains("Value::Null"));
+
+    let graphql_mod = fs::read_to_string(backend.join("src/graphql/mod.rs")).unwrap();
+    assert_eq!(graphql_mod, "pub mod root;\npub mod scalars;\n");
+    let cargo_toml = fs::read_to_string(backend.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("[dependencies]\nargon2 = \"0.5\"\nserde"));
+}
@@ -0,0 +1,323 @@
+use crate::db;
+use crate::db::schema::{self, Schema, TableSchema};
+use crate::db::transaction::{split_statements, Transaction};
+use crate::generators::fields;
+use crate::tests::utils::memory_config;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn write_entity(root: &Path, name: &str, body: &str) {
+    fs::create_dir_all(root.join("backend/src/entities")).unwrap();
+    fs::write(
+        root.join(format!("backend/src/entities/{}.rs", name.to_lowercase())),
+        format!("pub struct {} {{\n{}\n}}\n", name, body),
+    )
+    .unwrap();
+}
+
+async fn run(db: &surrealdb::Surreal<surrealdb::engine::any::Any>, sql: &[String]) {
+    let mut tx = Transaction::new();
+    for (i, statement) in split_statements(&sql.join("\n")).iter().enumerate() {
+        tx.push("Diff", i + 1, statement);
+    }
+    tx.commit(db).await.unwrap();
+}
+
+#[test]
+fn test_entity_diff_round_trips_through_database() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_entity(root, "User", "    pub name: String,\n    pub age: i32,");
+    write_entity(
+        root,
+        "Post",
+        "    pub title: String,\n\n    // Relations\n    pub user_id: ID,\n    pub user: Option<User>,",
+    );
+
+    let first = schema::diff(&Schema::default(), &Schema::from_entities(root).unwrap());
+    assert!(first.down.contains(&"REMOVE TABLE users;".to_string()));
+
+    write_entity(root, "User", "    pub name: String,\n    pub score: f64,");
+    let target = Schema::from_entities(root).unwrap();
+
+    let config = memory_config();
+
+    let (live, second, after_up, after_down) = db::block_on(async {
+        let db = db::connect(&config).await.unwrap();
+        run(&db, &first.up).await;
+
+        let live = Schema::from_database(&db).await.unwrap();
+        let second = schema::diff(&live, &target);
+        run(&db, &second.up).await;
+        let after_up = Schema::from_database(&db).await.unwrap();
+        run(&db, &second.down).await;
+        let after_down = Schema::from_database(&db).await.unwrap();
+        (live, second, after_up, after_down)
+    })
+    .unwrap();
+
+    assert_eq!(
+        second.up,
+        vec![
+            "REMOVE FIELD age ON users;",
+            "DEFINE FIELD score ON users TYPE float;",
+        ]
+    );
+    assert_eq!(after_up, target);
+    assert_eq!(after_down, live);
+    assert_eq!(
+        after_up.tables["posts"].fields["user_id"].kind,
+        "record<users>"
+    );
+}
+
+#[test]
+fn test_entity_validations_become_constraints() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_entity(
+        root,
+        "Account",
+        "    #[validate(required)]\n    pub nickname: Option<String>,\n    \
+         #[validate(custom = \"validate_unique\")]\n    #[validate(email)]\n    pub login: String,\n\n    \
+         // Relations\n    pub team_id: ID,\n    pub team: Option<Team>,",
+    );
+
+    let schema = Schema::from_entities(root).unwrap();
+    let accounts = &schema.tables["accounts"];
+    let definitions = accounts
+        .fields
+        .iter()
+        .map(|(name, field)| field.define("accounts", name, false))
+        .chain(
+            accounts
+                .indexes
+                .iter()
+                .map(|(name, index)| index.define("accounts", name, false)),
+        )
+        .collect::<Vec<_>>();
+
+    for expected in [
+        "DEFINE FIELD nickname ON accounts TYPE option<string> ASSERT $value != NONE;",
+        "DEFINE FIELD login ON accounts TYPE string ASSERT string::is::email($value);",
+        "DEFINE FIELD team_id ON accounts TYPE record<teams>;",
+        "DEFINE INDEX idx_accounts_login_unique ON accounts FIELDS login UNIQUE;",
+        "DEFINE INDEX idx_accounts_team ON accounts FIELDS team_id;",
+    ] {
+        assert!(
+            definitions.contains(&expected.to_string()),
+            "missing {}",
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_many_to_many_edge_table_relates_records() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_entity(root, "Tag", "    pub label: String,");
+    write_entity(
+        root,
+        "Post",
+        "    pub title: String,\n\n    // Relations\n    /// Graph edge ->tagged->tags\n    \
+         #[graphql(skip)]\n    #[serde(default)]\n    pub tags: Vec<Tag>,",
+    );
+
+    let target = Schema::from_entities(root).unwrap();
+    let edge = target.tables["tagged"].relation.clone().unwrap();
+    assert_eq!((edge.from.as_str(), edge.to.as_str()), ("posts", "tags"));
+    assert!(!target.tables["posts"].fields.contains_key("tags"));
+
+    let config = memory_config();
+
+    let (live, labels) = db::block_on(async {
+        let db = db::connect(&config).await.unwrap();
+        run(&db, &schema::diff(&Schema::default(), &target).up).await;
+        run(
+            &db,
+            &[
+                "CREATE posts:1 SET title = 'Hello';".to_string(),
+                "CREATE tags:rust SET label = 'rust';".to_string(),
+                "RELATE (type::thing('posts', '1'))->tagged->(type::thing('tags', 'rust'));"
+                    .to_string(),
+            ],
+        )
+        .await;
+
+        let live = Schema::from_database(&db).await.unwrap();
+        let labels: Vec<String> = db
+            .query("SELECT VALUE label FROM type::thing('posts', '1')->tagged->tags")
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        (live, labels)
+    })
+    .unwrap();
+
+    assert_eq!(live, target);
+    assert_eq!(labels, vec!["rust"]);
+}
+
+#[test]
+fn test_nested_object_fields_are_defined_individually() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_entity(
+        root,
+        "Customer",
+        "    pub name: String,\n    #[validate]\n    pub address: Address,\n    \
+         pub billing: Option<Address>,\n\n    // Relations\n}\n\n\
+         #[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject, InputObject, Validate)]\n\
+         #[graphql(input_name = \"AddressInput\")]\n\
+         pub struct Address {\n    #[validate(length(min = 3))]\n    pub street: String,\n    \
+         pub zip: Option<String>,",
+    );
+
+    let target = Schema::from_entities(root).unwrap();
+    let customers = &target.tables["customers"];
+    let definitions = customers
+        .fields
+        .iter()
+        .map(|(name, field)| field.define("customers", name, false))
+        .collect::<Vec<_>>();
+    for expected in [
+        "DEFINE FIELD address ON customers TYPE object;",
+        "DEFINE FIELD address.street ON customers TYPE string ASSERT string::len($value) >= 3;",
+        "DEFINE FIELD address.zip ON customers TYPE option<string>;",
+        "DEFINE FIELD billing ON customers TYPE option<object>;",
+        "DEFINE FIELD billing.street ON customers TYPE string ASSERT string::len($value) >= 3;",
+    ] {
+        assert!(
+            definitions.contains(&expected.to_string()),
+            "missing {}",
+            expected
+        );
+    }
+
+    let config = memory_config();
+
+    let (live, rejected) = db::block_on(async {
+        let db = db::connect(&config).await.unwrap();
+        run(&db, &schema::diff(&Schema::default(), &target).up).await;
+        run(
+            &db,
+            &[
+                "CREATE customers:1 SET name = 'Ada', address = { street: 'Main street' };"
+                    .to_string(),
+            ],
+        )
+        .await;
+        let rejected = db
+            .query("CREATE customers:2 SET name = 'Bob', address = { street: 'A' }")
+            .await
+            .unwrap()
+            .check()
+            .is_err();
+        (Schema::from_database(&db).await.unwrap(), rejected)
+    })
+    .unwrap();
+
+    assert_eq!(live, target);
+    assert!(rejected);
+}
+
+#[test]
+fn test_enum_fields_only_accept_their_values() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    write_entity(
+        root,
+        "Article",
+        "    pub title: String,\n    pub status: ArticleStatus,\n    \
+         pub review: Option<ArticleStatus>,\n\n    // Relations\n}\n\n\
+         #[derive(Enum, Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]\n\
+         pub enum ArticleStatus {\n    #[default]\n    #[serde(rename = \"draft\")]\n    Draft,\n    \
+         #[serde(rename = \"in_review\")]\n    InReview,",
+    );
+
+    let target = Schema::from_entities(root).unwrap();
+    let articles = &target.tables["articles"];
+    assert_eq!(
+        articles.fields["status"].define("articles", "status", false),
+        "DEFINE FIELD status ON articles TYPE string ASSERT $value INSIDE ['draft', 'in_review'];"
+    );
+    assert_eq!(
+        articles.fields["review"].assert.as_deref(),
+        Some("$value = NONE OR ($value INSIDE ['draft', 'in_review'])")
+    );
+
+    let config = memory_config();
+
+    let (live, rejected) = db::block_on(async {
+        let db = db::connect(&config).await.unwrap();
+        run(&db, &schema::diff(&Schema::default(), &target).up).await;
+        run(
+            &db,
+            &["CREATE articles:1 SET title = 'Hello', status = 'in_review';".to_string()],
+        )
+        .await;
+        let rejected = db
+            .query("CREATE articles:2 SET title = 'Bye', status = 'archived'")
+            .await
+            .unwrap()
+            .check()
+            .is_err();
+        (Schema::from_database(&db).await.unwrap(), rejected)
+    })
+    .unwrap();
+
+    assert_eq!(live, target);
+    assert!(rejected);
+}
+
+#[test]
+fn test_rust_types_map_to_surql_types() {
+    for (rust_type, kind) in [
+        ("Uuid", "uuid"),
+        ("Money", "decimal"),
+        ("Vec<String>", "array<string>"),
+        ("Json", "object"),
+        ("Option<i32>", "option<int>"),
+        ("Option<Vec<Uuid>>", "option<array<uuid>>"),
+        ("DateTime<Utc>", "datetime"),
+        ("Password", "string"),
+    ] {
+        assert_eq!(schema::surql_type(rust_type), kind, "{}", rust_type);
+    }
+
+    let args = [
+        "key:Uuid",
+        "price:Money|min=0",
+        "tags:Vec<String>",
+        "meta:Json",
+        "address?:Address{street:String}",
+        "note?:String|max_length=20",
+    ]
+    .map(String::from);
+    let info = fields::parse("Product", &args).unwrap();
+    let table = TableSchema::from_entity("products", &info);
+    let define = |field: &str| table.fields[field].define("products", field, false);
+
+    assert_eq!(define("key"), "DEFINE FIELD key ON products TYPE uuid;");
+    assert_eq!(
+        define("price"),
+        "DEFINE FIELD price ON products TYPE decimal ASSERT $value >= 0;"
+    );
+    assert_eq!(
+        define("tags"),
+        "DEFINE FIELD tags ON products TYPE array<string>;"
+    );
+    assert_eq!(define("meta"), "DEFINE FIELD meta ON products TYPE object;");
+    assert_eq!(
+        define("address"),
+        "DEFINE FIELD address ON products TYPE option<object>;"
+    );
+    assert_eq!(
+        define("note"),
+        "DEFINE FIELD note ON products TYPE option<string> \
+         ASSERT $value = NONE OR (string::len($value) <= 20);"
+    );
+}
@@ -0,0 +1,143 @@
+use crate::db::schema::Schema;
+use crate::generators::entity::RelationType;
+use crate::generators::schema_file::{SchemaFile, SCHEMA_TEMPLATE};
+
+const SCHEMA: &str = r#"
+[[entity]]
+name = "article"
+
+[[entity.field]]
+name = "title"
+type = "String"
+validate = ["required", "min_length=3"]
+
+[[entity.field]]
+name = "views"
+type = "i32"
+default = 0
+
+[[entity.field]]
+name = "bio"
+type = "String"
+optional = true
+
+[[entity.field]]
+name = "tags"
+type = "Vec<Uuid>"
+
+[[entity.field]]
+name = "rank"
+type = "Option<i32>"
+
+[[entity.field]]
+name = "status"
+enum = ["draft", "published"]
+
+[[entity.field]]
+name = "address"
+type = "Address"
+
+[[entity.field.field]]
+name = "street"
+type = "String"
+
+[[entity.relation]]
+kind = "manyToMany"
+target = "Tag"
+via = "tagged"
+"#;
+
+#[test]
+fn test_schema_file_describes_entities() {
+    let infos = SchemaFile::parse(SCHEMA).unwrap().entity_infos().unwrap();
+    let article = &infos[0];
+
+    assert_eq!(article.name, "Article");
+    let types = article
+        .fields
+        .iter()
+        .map(|(name, type_, _)| (name.as_str(), type_.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        [
+            ("title", "String"),
+            ("views", "i32"),
+            ("bio", "Option<String>"),
+            ("tags", "Vec<Uuid>"),
+            ("rank", "Option<i32>"),
+            ("status", "ArticleStatus"),
+            ("address", "Address")
+        ]
+    );
+    assert!(article.fields[0].2.required);
+    assert_eq!(article.fields[0].2.min_length, Some(3));
+    assert_eq!(article.fields[1].2.default.as_deref(), Some("0"));
+    assert_eq!(article.enums[0].values(), ["draft", "published"]);
+    assert_eq!(article.objects[0].fields[0].0, "street");
+    assert_eq!(
+        article.relations,
+        [RelationType::ManyToMany("Tag".into(), "tagged".into())]
+    );
+
+    // La migration est calculée sur ces entités, sans relire les fichiers Rust générés
+    let schema = Schema::from_infos(&infos);
+    let articles = &schema.tables["articles"];
+    assert!(articles.fields.contains_key("rank"));
+    assert!(articles.fields.contains_key("address.street"));
+    assert!(schema.tables["tagged"].relation.is_some());
+
+    // Le modèle écrit par `nebulis new` est un schéma valide, sans entité
+    let template = SchemaFile::parse(SCHEMA_TEMPLATE).unwrap();
+    assert!(template.entity_infos().unwrap().is_empty());
+}
+
+#[test]
+fn test_schema_file_errors_name_the_entity() {
+    let missing_edge = "[[entity]]\nname = \"Post\"\n[[entity.relation]]\nkind = \"manyToMany\"\ntarget = \"Tag\"\n";
+    let error = SchemaFile::parse(missing_edge)
+        .unwrap()
+        .entity_infos()
+        .unwrap_err();
+    assert!(
+        error.starts_with("Entity Post: Missing edge table"),
+        "{}",
+        error
+    );
+
+    let bad_type =
+        "[[entity]]\nname = \"Post\"\n[[entity.field]]\nname = \"title\"\ntype = \"Strin\"\n";
+    let error = SchemaFile::parse(bad_type)
+        .unwrap()
+        .entity_infos()
+        .unwrap_err();
+    assert!(
+        error.starts_with("Entity Post: Invalid type: Strin"),
+        "{}",
+        error
+    );
+
+    let bad_default = "[[entity]]\nname = \"Post\"\n[[entity.field]]\nname = \"views\"\ntype = \"i32\"\ndefault = \"many\"\n";
+    let error = SchemaFile::parse(bad_default)
+        .unwrap()
+        .entity_infos()
+        .unwrap_err();
+    assert!(
+        error.starts_with("Entity Post: Field views: Invalid default value many"),
+        "{}",
+        error
+    );
+
+    let twice_optional = "[[entity]]\nname = \"Post\"\n[[entity.field]]\nname = \"bio\"\ntype = \"Option<String>\"\noptional = true\n";
+    let error = SchemaFile::parse(twice_optional)
+        .unwrap()
+        .entity_infos()
+        .unwrap_err();
+    assert!(
+        error.starts_with("Entity Post: Field bio is marked optional twice"),
+        "{}",
+        error
+    );
+
+    assert!(SchemaFile::parse("[[entity]]\nname = \"Post\"\ncolor = \"red\"\n").is_err());
+}
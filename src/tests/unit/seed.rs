@@ -0,0 +1,107 @@
+use crate::db::schema::TableSchema;
+use crate::db::{self, seeder};
+use crate::generators::entity::EntityInfo;
+use crate::generators::fields;
+use crate::generators::seed::generate_seed_content;
+use crate::tests::utils::memory_config;
+use std::fs;
+use surrealdb::Response;
+use tempfile::tempdir;
+
+const POST_ENTITY: &str = r#"
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Post {
+    pub id: ID,
+    #[validate(required, length(min = 5, max = 20))]
+    pub title: String,
+    #[validate(email)]
+    pub contact: String,
+    pub website: Option<Url>,
+    pub price: Money,
+    #[validate(custom = "validate_unique")]
+    pub code: String,
+    #[validate(range(min = 10, max = 20))]
+    pub rating: i32,
+    #[validate(custom = "validate_unique", range(min = 1, max = 20))]
+    pub rank: i32,
+    #[validate(custom = "validate_unique")]
+    pub phone: Phone,
+    pub slug: Slug,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+
+    // Relations
+    pub user_id: ID,
+    pub user: Option<User>,
+}
+"#;
+
+#[test]
+fn test_seed_values_satisfy_validations() {
+    let info = EntityInfo::parse(POST_ENTITY).unwrap();
+    let related = vec![(
+        "User".to_string(),
+        vec!["users:1".to_string(), "users:2".to_string()],
+    )];
+    assert!(info.fields.iter().all(|(name, _, validation)| {
+        !["rank", "phone"].contains(&name.as_str()) || validation.unique
+    }));
+    let content = generate_seed_content("Post", &info, 20, &related).unwrap();
+
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("database/seeds/development")).unwrap();
+    fs::write(root.join("database/seeds/development/post.surql"), &content).unwrap();
+
+    let config = memory_config();
+
+    let posts = db::block_on(async {
+        let db = db::connect(&config).await.unwrap();
+        // Le schéma généré pour l'entité vérifie les valeurs avec ses ASSERT
+        let schema = TableSchema::from_entity("posts", &info).define("posts");
+        db.query(schema.join("\n"))
+            .await
+            .and_then(Response::check)
+            .unwrap();
+        // Relancer les seeds met à jour les mêmes enregistrements, sans conflit d'index UNIQUE
+        seeder::run(&db, root, seeder::DEFAULT_ENV).await.unwrap();
+        seeder::run(&db, root, seeder::DEFAULT_ENV).await.unwrap();
+        let mut response = db
+            .query("SELECT title, contact, rating, slug, <string> user_id AS user_id FROM posts")
+            .await
+            .unwrap();
+        let posts: Vec<serde_json::Value> = response.take(0).unwrap();
+        posts
+    })
+    .unwrap();
+
+    assert_eq!(posts.len(), 20);
+    for post in posts {
+        let title = post["title"].as_str().unwrap();
+        let rating = post["rating"].as_i64().unwrap();
+        assert!((5..=20).contains(&title.chars().count()), "{}", title);
+        assert!(post["contact"].as_str().unwrap().ends_with("@example.com"));
+        assert!((10..=20).contains(&rating));
+        assert!(!post["slug"].as_str().unwrap().contains(' '));
+        assert!(post["user_id"].as_str().unwrap().starts_with("users:"));
+    }
+    assert_eq!(
+        content,
+        generate_seed_content("Post", &info, 20, &related).unwrap()
+    );
+
+    // 21 rangs uniques ne tiennent pas dans [1, 20]
+    let error = generate_seed_content("Post", &info, 21, &related).unwrap_err();
+    assert!(error.starts_with("Field rank: unique values"), "{}", error);
+}
+
+#[test]
+fn test_seed_rejects_min_length_above_max_length() {
+    let args = ["title:String|min_length=10|max_length=5"].map(String::from);
+    let info = fields::parse("Note", &args).unwrap();
+    let error = generate_seed_content("Note", &info, 1, &[]).unwrap_err();
+    assert_eq!(
+        error,
+        "Field title: min_length 10 is greater than max_length 5"
+    );
+}
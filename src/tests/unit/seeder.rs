@@ -0,0 +1,75 @@
+use crate::db::{self, seeder};
+use crate::tests::utils::memory_config;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_seeds_only_run_for_selected_env() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("database/seeds/production")).unwrap();
+    fs::create_dir_all(root.join("database/seeds/development")).unwrap();
+    fs::write(
+        root.join("database/seeds/01_roles.surql"),
+        "-- env: *\nCREATE role:admin SET name = 'admin';",
+    )
+    .unwrap();
+    fs::write(
+        root.join("database/seeds/02_owner.surql"),
+        "-- env: production\nCREATE user:root SET name = 'root';",
+    )
+    .unwrap();
+    fs::write(
+        root.join("database/seeds/development/users.surql"),
+        "CREATE user:demo SET name = 'demo';",
+    )
+    .unwrap();
+    fs::write(
+        root.join("database/seeds/production/users.surql"),
+        "CREATE user:owner SET name = 'owner';",
+    )
+    .unwrap();
+
+    let config = memory_config();
+
+    let (seeded, users) = db::block_on(async {
+        let db = db::connect(&config).await.unwrap();
+        let seeded = seeder::run(&db, root, "development").await.unwrap();
+        let mut response = db.query("SELECT VALUE name FROM user").await.unwrap();
+        let users: Vec<String> = response.take(0).unwrap();
+        (seeded, users)
+    })
+    .unwrap();
+
+    assert_eq!(seeded, vec!["01_roles.surql", "development/users.surql"]);
+    assert_eq!(users, vec!["demo"]);
+    assert!(seeder::discover(root, "../production").is_err());
+
+    // Un seed racine sans déclaration d'environnement est refusé plutôt que lancé partout
+    fs::write(root.join("database/seeds/03_any.surql"), "CREATE user:any;").unwrap();
+    let error = seeder::discover(root, "development").unwrap_err();
+    assert!(
+        error.contains("03_any.surql must declare its environments"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_seed_env_must_match_the_database() {
+    let mut config = memory_config();
+    assert!(seeder::check_env(&config, "development").is_ok());
+    assert!(seeder::check_env(&config, "production").is_err());
+
+    config.env = Some("development".to_string());
+    assert!(seeder::check_env(&config, "production").is_err());
+
+    config.host = "wss://db.example.com".to_string();
+    config.env = None;
+    assert!(seeder::check_env(&config, "production").is_ok());
+    config.host = "127.0.0.1".to_string();
+    config.port = "8000".to_string();
+    assert!(seeder::check_env(&config, "production").is_err());
+    config.env = Some("production".to_string());
+    assert!(seeder::check_env(&config, "production").is_ok());
+}
@@ -0,0 +1,256 @@
+use crate::db;
+use crate::db::schema::{self, Schema};
+use crate::generators::entity::EntityInfo;
+use crate::generators::fields;
+use crate::generators::service::generate_repository_content;
+use crate::tests::utils::memory_config;
+use regex::Regex;
+use surrealdb::Response;
+
+const ARTICLE_ENTITY: &str = r#"
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject, Validate)]
+pub struct Article {
+    pub title: String,
+    pub views: i32,
+    pub status: ArticleStatus,
+    #[validate]
+    pub address: Address,
+
+    // Relations
+    pub user_id: ID,
+    pub user: Option<User>,
+    pub comment: Vec<Comment>,
+    /// Graph edge ->tagged->tags
+    #[graphql(skip)]
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Enum, Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ArticleStatus {
+    #[default]
+    #[serde(rename = "draft")]
+    Draft,
+    #[serde(rename = "published")]
+    Published,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject, InputObject, Validate)]
+#[graphql(input_name = "AddressInput")]
+pub struct Address {
+    pub city: String,
+}
+"#;
+
+#[test]
+fn test_repository_filters_are_valid_surrealql() {
+    let info = EntityInfo::parse(ARTICLE_ENTITY).unwrap();
+    let content = generate_repository_content(&info);
+
+    let condition_regex = Regex::new(r#"conditions\.push\("(.+)"\);"#).unwrap();
+    let conditions = condition_regex
+        .captures_iter(&content)
+        .map(|cap| cap[1].to_string())
+        .collect::<Vec<_>>();
+    let params = Regex::new(r"\$(\w+)")
+        .unwrap()
+        .captures_iter(&conditions.join(" "))
+        .map(|cap| cap[1].to_string())
+        .filter(|param| param != "parent")
+        .collect::<Vec<_>>();
+
+    for expected in [
+        "deleted_at = NONE",
+        "string::contains(string::lowercase(address.city), string::lowercase($address_city_contains))",
+        "status INSIDE $status_in",
+        "(user_id != NONE) = $has_user",
+        "count(->tagged[WHERE record::id(out) INSIDE $tag_ids]) > 0",
+    ] {
+        assert!(conditions.contains(&expected.to_string()), "missing {}", expected);
+    }
+
+    let config = memory_config();
+    let mut target = Schema::default();
+    target.tables.insert(
+        "articles".to_string(),
+        schema::TableSchema::from_entity("articles", &info),
+    );
+
+    let titles: Vec<String> = db::block_on(async {
+        let db = db::connect(&config).await.unwrap();
+        let mut setup = schema::diff(&Schema::default(), &target).up;
+        setup.push(
+            "CREATE articles:one SET title = 'Hello', views = 3, status = 'published', \
+             address = { city: 'Lyon' }, user_id = users:1;"
+                .to_string(),
+        );
+        setup.push("RELATE articles:one->tagged->tags:rust;".to_string());
+        db.query(setup.join("\n"))
+            .await
+            .and_then(Response::check)
+            .unwrap();
+
+        let mut query = db.query(format!(
+            "SELECT VALUE title FROM articles WHERE {} ORDER BY title DESC LIMIT 20 START 0",
+            conditions.join(" AND ")
+        ));
+        for param in &params {
+            let value = match param.as_str() {
+                "from_date" => serde_json::json!("2020-01-01T00:00:00Z"),
+                "to_date" => serde_json::json!("2100-01-01T00:00:00Z"),
+                "title_contains" => serde_json::json!("hell"),
+                "views_min" | "comment_count_max" => serde_json::json!(3),
+                "views_max" => serde_json::json!(10),
+                "status_in" => serde_json::json!(["published"]),
+                "status_not_in" => serde_json::json!(["draft"]),
+                "address_city_contains" => serde_json::json!("lyo"),
+                "has_user" => serde_json::json!(true),
+                "has_comment" => serde_json::json!(false),
+                "comment_count_min" => serde_json::json!(0),
+                "tag_ids" => serde_json::json!(["rust"]),
+                "cursor" => serde_json::json!("a"),
+                other => panic!("unexpected parameter {}", other),
+            };
+            query = query.bind((param.clone(), value));
+        }
+        query.await.unwrap().take(0).unwrap()
+    })
+    .unwrap();
+
+    assert_eq!(titles, vec!["Hello"]);
+}
+
+/// Requête SurrealQL d'une méthode du repository généré (`CREATE ...`, `UPDATE ...`)
+fn repository_query(content: &str, prefix: &str) -> String {
+    Regex::new(&format!(r#"\.query\("({}[^"]*)"\)"#, prefix))
+        .unwrap()
+        .captures(content)
+        .unwrap()[1]
+        .to_string()
+}
+
+/// Chaîne de format d'une variable de `find_all` (`let cursor_condition = format!("...")`)
+fn repository_format(content: &str, variable: &str) -> String {
+    Regex::new(&format!(r#"let {} = format!\(\s*"([^"]+)""#, variable))
+        .unwrap()
+        .captures(content)
+        .unwrap()[1]
+        .to_string()
+}
+
+#[test]
+fn test_repository_creates_and_pages_belongs_to_records() {
+    let args = ["body:String", "belongsTo->User"].map(String::from);
+    let info = fields::parse("Comment", &args).unwrap();
+    let content = generate_repository_content(&info);
+
+    let create = repository_query(&content, "CREATE");
+    assert_eq!(
+        create,
+        "CREATE comments SET body = $body, user_id = type::thing('users', $user_id)"
+    );
+    assert!(content.contains(r#".bind(("user_id", input.user_id.0))"#));
+
+    // Page suivante : après la valeur de tri du curseur, puis après son identifiant
+    let cursor_value = repository_format(&content, "cursor_value").replace("{}", "created_at");
+    let cursor_condition = repository_format(&content, "cursor_condition")
+        .replace("{0}", "created_at")
+        .replace("{1}", "<")
+        .replace("{2}", &cursor_value);
+    assert!(content.contains(r#"" ORDER BY {0} {1}, id {1} LIMIT $limit START $start""#));
+
+    let mut target = Schema::default();
+    target.tables.insert(
+        "comments".to_string(),
+        schema::TableSchema::from_entity("comments", &info),
+    );
+
+    let (authors, pages, all) = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        db.query(schema::diff(&Schema::default(), &target).up.join("\n"))
+            .await
+            .and_then(Response::check)
+            .unwrap();
+        for i in 0..5 {
+            db.query(create.as_str())
+                .bind(("body", format!("comment {}", i)))
+                .bind(("user_id", "alice"))
+                .await
+                .and_then(Response::check)
+                .unwrap();
+        }
+
+        let mut response = db
+            .query("SELECT VALUE <string> user_id FROM comments")
+            .await
+            .unwrap();
+        let authors: Vec<String> = response.take(0).unwrap();
+
+        let order = " ORDER BY created_at DESC, id DESC";
+        let mut pages = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut sql =
+                "SELECT record::id(id) AS key, id, created_at FROM comments WHERE deleted_at = NONE".to_string();
+            if cursor.is_some() {
+                sql.push_str(&format!(" AND {}", cursor_condition));
+            }
+            sql.push_str(&format!("{} LIMIT 2", order));
+            let mut response = db
+                .query(sql)
+                .bind(("cursor", cursor.clone()))
+                .await
+                .unwrap();
+            let page: Vec<String> = response.take((0, "key")).unwrap();
+            if page.is_empty() {
+                break;
+            }
+            cursor = page.last().cloned();
+            pages.extend(page);
+        }
+
+        let mut response = db
+            .query(format!(
+                "SELECT record::id(id) AS key, id, created_at FROM comments{}",
+                order
+            ))
+            .await
+            .unwrap();
+        let all: Vec<String> = response.take((0, "key")).unwrap();
+        (authors, pages, all)
+    })
+    .unwrap();
+
+    assert_eq!(authors, vec!["users:alice"; 5]);
+    assert_eq!(all.len(), 5);
+    assert_eq!(pages, all);
+}
+
+#[test]
+fn test_repository_of_entity_without_fields_is_valid_surrealql() {
+    let info = fields::parse("Marker", &[]).unwrap();
+    let content = generate_repository_content(&info);
+    let create = repository_query(&content, "CREATE");
+    let update = repository_query(&content, "UPDATE type::thing\\('markers', \\$id\\) WHERE");
+
+    let markers: Vec<String> = db::block_on(async {
+        let db = db::connect(&memory_config()).await.unwrap();
+        db.query(create.replace("CREATE markers", "CREATE markers:one"))
+            .await
+            .and_then(Response::check)
+            .unwrap();
+        db.query(update)
+            .bind(("id", "one"))
+            .await
+            .and_then(Response::check)
+            .unwrap();
+        let mut response = db
+            .query("SELECT VALUE record::id(id) FROM markers")
+            .await
+            .unwrap();
+        response.take(0).unwrap()
+    })
+    .unwrap();
+
+    assert_eq!(markers, vec!["one"]);
+}
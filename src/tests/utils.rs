@@ -1,17 +1,12 @@
+use crate::db::DbConfig;
 use std::path::Path;
 
-pub fn cleanup_test_dir(path: &Path) {
-    if path.exists() {
-        std::fs::remove_dir_all(path).unwrap();
-    }
-}
-
 pub fn assert_project_structure(project_path: &Path) {
     // Backend
     assert!(project_path.join("backend/Cargo.toml").exists());
     assert!(project_path.join("backend/src/main.rs").exists());
     assert!(project_path.join("backend/src/graphql").exists());
-    assert!(project_path.join("backend/src/db").exists());
+    assert!(project_path.join("backend/src/db.rs").exists());
 
     // Frontend
     assert!(project_path.join("frontend/package.json").exists());
@@ -19,3 +14,16 @@ pub fn assert_project_structure(project_path: &Path) {
     // Docker
     assert!(project_path.join("docker-compose.yml").exists());
 }
+
+/// Connexion à une base SurrealDB en mémoire, propre à chaque test
+pub fn memory_config() -> DbConfig {
+    DbConfig {
+        host: "mem://".to_string(),
+        port: String::new(),
+        user: "root".to_string(),
+        password: "root".to_string(),
+        namespace: "test".to_string(),
+        database: "test".to_string(),
+        env: None,
+    }
+}